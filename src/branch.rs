@@ -0,0 +1,220 @@
+use anyhow::Context;
+use regex::Regex;
+
+use crate::config::BranchConfig;
+use crate::errors::GrovError;
+
+/// Why `name` is not a valid branch name, following the rules of
+/// `git check-ref-format --branch`.
+pub fn ref_format_problem(name: &str) -> Option<&'static str> {
+    if name.is_empty() {
+        return Some("cannot be empty");
+    }
+    if name.starts_with('-') {
+        return Some("cannot start with \"-\"");
+    }
+    if name == "HEAD" || name == "@" {
+        return Some("is reserved by git");
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| c.is_ascii_control() || " ~^:?*[\\".contains(*c))
+    {
+        return Some(if c == ' ' {
+            "cannot contain spaces"
+        } else if c.is_ascii_control() {
+            "cannot contain control characters"
+        } else {
+            "cannot contain any of ~ ^ : ? * [ \\"
+        });
+    }
+    if name.contains("..") {
+        return Some("cannot contain \"..\"");
+    }
+    if name.contains("@{") {
+        return Some("cannot contain \"@{\"");
+    }
+    if name.starts_with('/') || name.ends_with('/') || name.contains("//") {
+        return Some("cannot start or end with \"/\" or contain \"//\"");
+    }
+    if name.ends_with('.') {
+        return Some("cannot end with \".\"");
+    }
+    for component in name.split('/') {
+        if component.starts_with('.') {
+            return Some("path components cannot start with \".\"");
+        }
+        if component.ends_with(".lock") {
+            return Some("path components cannot end with \".lock\"");
+        }
+    }
+    None
+}
+
+/// Reject names git would refuse as a branch.
+pub fn check_ref_format(name: &str) -> Result<(), GrovError> {
+    match ref_format_problem(name) {
+        Some(reason) => Err(invalid(name, reason)),
+        None => Ok(()),
+    }
+}
+
+/// The naming rules for new branches: git's, plus the `[branch]` policy
+/// from config.
+#[derive(Debug, Default, Clone)]
+pub struct BranchPolicy {
+    prefixes: Vec<String>,
+    pattern: Option<Regex>,
+}
+
+impl BranchPolicy {
+    pub fn from_config(config: &BranchConfig) -> anyhow::Result<Self> {
+        let pattern = match config.pattern.as_str() {
+            "" => None,
+            pattern => Some(
+                Regex::new(pattern)
+                    .with_context(|| format!("invalid branch.pattern `{pattern}`"))?,
+            ),
+        };
+        Ok(Self {
+            prefixes: config.prefixes.clone(),
+            pattern,
+        })
+    }
+
+    /// Why `name` may not be used for a new branch, if it may not.
+    pub fn problem(&self, name: &str) -> Option<String> {
+        if let Some(reason) = ref_format_problem(name) {
+            return Some(reason.to_string());
+        }
+        if !self.prefixes.is_empty() && !self.prefixes.iter().any(|p| name.starts_with(p)) {
+            return Some(format!("must start with {}", self.prefixes.join(", ")));
+        }
+        if let Some(pattern) = &self.pattern
+            && !pattern.is_match(name)
+        {
+            return Some(format!("must match `{}`", pattern.as_str()));
+        }
+        None
+    }
+
+    pub fn check(&self, name: &str) -> Result<(), GrovError> {
+        match self.problem(name) {
+            Some(reason) => Err(invalid(name, &reason)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn invalid(name: &str, reason: &str) -> GrovError {
+    GrovError::InvalidBranchName {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agrees_with_git_check_ref_format() {
+        let names = [
+            "feat/login",
+            "héllo",
+            "a@b",
+            "x/HEAD",
+            "a/@/b",
+            "",
+            "-x",
+            "HEAD",
+            "a b",
+            "a~1",
+            "a^",
+            "a:b",
+            "a?",
+            "a*",
+            "a[b",
+            "a\\b",
+            "a\tb",
+            "a..b",
+            "a@{1}",
+            "/a",
+            "a/",
+            "a//b",
+            "a.",
+            ".a",
+            "a/.b",
+            "a/./b",
+            "a.lock",
+            "a.lock/b",
+            "a/b.lock/c",
+        ];
+        for name in names {
+            let git_ok = std::process::Command::new("git")
+                .args(["check-ref-format", "--branch", name])
+                .current_dir(std::env::temp_dir())
+                .output()
+                .unwrap()
+                .status
+                .success();
+            assert_eq!(
+                ref_format_problem(name).is_none(),
+                git_ok,
+                "disagreement on {name:?}: {:?}",
+                ref_format_problem(name)
+            );
+        }
+    }
+
+    #[test]
+    fn reports_reason() {
+        let err = check_ref_format("feat..x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid branch name `feat..x`: cannot contain \"..\""
+        );
+    }
+
+    #[test]
+    fn enforces_prefixes() {
+        let policy = BranchPolicy::from_config(&BranchConfig {
+            prefixes: vec!["feat/".into(), "fix/".into()],
+            pattern: String::new(),
+        })
+        .unwrap();
+        assert_eq!(policy.problem("fix/crash"), None);
+        assert_eq!(
+            policy.problem("login").as_deref(),
+            Some("must start with feat/, fix/")
+        );
+        // git's own rules come first
+        assert_eq!(
+            policy.problem("feat/a..b").as_deref(),
+            Some("cannot contain \"..\"")
+        );
+    }
+
+    #[test]
+    fn enforces_pattern() {
+        let policy = BranchPolicy::from_config(&BranchConfig {
+            prefixes: vec![],
+            pattern: r"[A-Z]+-\d+".into(),
+        })
+        .unwrap();
+        assert!(policy.check("feat/ABC-12-login").is_ok());
+        assert!(matches!(
+            policy.check("feat/login"),
+            Err(GrovError::InvalidBranchName { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_pattern() {
+        let config = BranchConfig {
+            prefixes: vec![],
+            pattern: "(".into(),
+        };
+        assert!(BranchPolicy::from_config(&config).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 
 /// An opinionated bare-repo-only git worktree manager
@@ -15,9 +15,28 @@ pub struct Cli {
 pub enum Commands {
     /// Clone a repo as bare and create an initial worktree
     Init {
+        /// Repository URL to clone
+        #[arg(long)]
+        url: Option<String>,
+
+        /// Project directory name (defaults to the repository name)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Worktree directory prefix (blank for none)
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Branch for the initial worktree (defaults to the remote's default branch)
+        #[arg(long)]
+        branch: Option<String>,
+
         /// Parent directory to clone into (defaults to current directory)
         #[arg(long)]
         path: Option<PathBuf>,
+        /// Skip hooks configured in .grov.toml
+        #[arg(long)]
+        no_hooks: bool,
     },
 
     /// Create a new worktree for a branch
@@ -32,6 +51,12 @@ pub enum Commands {
         /// Custom path for the worktree
         #[arg(long)]
         path: Option<PathBuf>,
+        /// Worktree to copy configured files from (branch or directory name)
+        #[arg(long, value_name = "NAME")]
+        copy_from: Option<String>,
+        /// Skip hooks configured in .grov.toml
+        #[arg(long)]
+        no_hooks: bool,
     },
 
     /// List all worktrees
@@ -40,17 +65,48 @@ pub enum Commands {
         /// Show only branch names, one per line
         #[arg(long)]
         compact: bool,
+
+        /// Output format (json, tsv and porcelain are meant for scripts)
+        #[arg(long, value_enum, default_value_t = ListFormat::Human, conflicts_with = "compact")]
+        format: ListFormat,
+
+        /// Extra columns to show, comma-separated (defaults to list.columns)
+        #[arg(long, value_enum, value_delimiter = ',', conflicts_with = "compact")]
+        columns: Vec<ListColumn>,
+
+        /// Show every column
+        #[arg(long, conflicts_with_all = ["compact", "columns"])]
+        long: bool,
+
+        /// Order worktrees by branch name, last commit or status
+        #[arg(long, value_enum)]
+        sort: Option<ListSort>,
+
+        #[command(flatten)]
+        filters: ListFilters,
     },
 
+    /// Show details of the current worktree
+    #[command(alias = "st")]
+    Status {
+        /// Output format (json is meant for prompts and scripts)
+        #[arg(long, value_enum, default_value_t = StatusFormat::Human)]
+        format: StatusFormat,
+    },
+
+    /// Full-screen dashboard of all worktrees
+    Ui,
+
     /// Remove a worktree
     #[command(alias = "rm")]
     Remove {
         /// Worktree name or branch to remove (prompted if not provided)
+        #[arg(conflicts_with = "merged")]
         name: Option<String>,
 
         /// How to interpret the name when resolving a worktree
-        #[arg(long = "match", value_enum, default_value_t = RemoveMatchMode::Auto)]
-        match_mode: RemoveMatchMode,
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Auto)]
+        match_mode: MatchMode,
 
         /// Also delete the local branch
         #[arg(long)]
@@ -59,6 +115,124 @@ pub enum Commands {
         /// Force removal even if worktree has uncommitted changes
         #[arg(long)]
         force: bool,
+
+        /// Remove all worktrees whose branch is merged into the default branch
+        /// or whose upstream is gone, along with their local branches
+        #[arg(long)]
+        merged: bool,
+
+        /// Remove every merged worktree without prompting
+        #[arg(long, short, requires = "merged")]
+        yes: bool,
+
+        /// Allow removing worktrees of protected branches (worktree.protected)
+        #[arg(long)]
+        allow_protected: bool,
+        /// Skip hooks configured in .grov.toml
+        #[arg(long)]
+        no_hooks: bool,
+    },
+
+    /// Rename a worktree's branch and move its directory to match
+    #[command(name = "mv", alias = "move")]
+    Move {
+        /// Worktree name or branch to rename
+        name: String,
+
+        /// New branch name
+        new_branch: String,
+
+        /// How to interpret the name when resolving a worktree
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Auto)]
+        match_mode: MatchMode,
+
+        /// Move the worktree here instead of the configured location
+        #[arg(long)]
+        path: Option<PathBuf>,
+
+        /// Track origin/<new-branch> instead of the old upstream
+        #[arg(long)]
+        set_upstream: bool,
+    },
+
+    /// Lock a worktree so it cannot be moved, removed or pruned
+    Lock {
+        /// Worktree name or branch to lock
+        name: String,
+
+        /// How to interpret the name when resolving a worktree
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Auto)]
+        match_mode: MatchMode,
+
+        /// Why the worktree is locked, shown by `grov list`
+        #[arg(long)]
+        reason: Option<String>,
+    },
+
+    /// Unlock a locked worktree
+    Unlock {
+        /// Worktree name or branch to unlock
+        name: String,
+
+        /// How to interpret the name when resolving a worktree
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Auto)]
+        match_mode: MatchMode,
+    },
+
+    /// Run a command in every worktree
+    #[command(alias = "exec")]
+    Foreach {
+        /// Only worktrees with uncommitted changes
+        #[arg(long, conflicts_with = "clean")]
+        dirty: bool,
+
+        /// Only worktrees without uncommitted changes
+        #[arg(long)]
+        clean: bool,
+
+        /// Only worktrees whose branch matches this glob (e.g. `feature/*`)
+        #[arg(long, value_name = "GLOB")]
+        branch: Option<String>,
+
+        /// Number of worktrees to run in parallel (output is prefixed when > 1)
+        #[arg(long, short, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+
+        /// Command and arguments to run, after `--`
+        #[arg(required = true, last = true, value_name = "COMMAND")]
+        command: Vec<String>,
+    },
+
+    /// Clean up missing, orphaned and stale worktrees
+    Prune {
+        /// Only report what would be pruned
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Prune without prompting; only deletes directories with --delete-dirs
+        #[arg(long, short)]
+        yes: bool,
+
+        /// Allow deleting orphaned and broken worktree directories
+        #[arg(long)]
+        delete_dirs: bool,
+    },
+
+    /// Switch to an existing worktree (requires shell integration to change directory)
+    #[command(alias = "sw")]
+    Switch {
+        /// Worktree name or branch to switch to
+        name: String,
+
+        /// How to interpret the name when resolving a worktree
+        #[arg(long = "match", value_enum, default_value_t = MatchMode::Auto)]
+        match_mode: MatchMode,
+    },
+
+    /// Print a shell wrapper that lets grov change the current directory
+    ShellInit {
+        /// Shell to generate the wrapper for
+        shell: InitShell,
     },
 
     /// Generate shell completions
@@ -66,10 +240,62 @@ pub enum Commands {
         /// Shell to generate completions for
         shell: Shell,
     },
+
+    /// Inspect grov configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Print the effective configuration (global, repo and environment merged)
+    Show {
+        /// Prefix each key with where its value comes from
+        #[arg(long)]
+        origin: bool,
+    },
+
+    /// Print the effective value of a key
+    Get {
+        /// Dotted key, e.g. worktree.prefix
+        key: String,
+        /// Read the user-level config only
+        #[arg(long)]
+        global: bool,
+    },
+
+    /// Set a key in the project's .grov.toml (lists take several values)
+    Set {
+        /// Dotted key, e.g. worktree.prefix
+        key: String,
+        /// New value; list keys accept several values or a TOML array
+        #[arg(required = true, num_args = 1.., allow_hyphen_values = true)]
+        values: Vec<String>,
+        /// Write to the user-level config instead
+        #[arg(long)]
+        global: bool,
+    },
+
+    /// Remove a key from the project's .grov.toml
+    Unset {
+        /// Dotted key, e.g. worktree.prefix
+        key: String,
+        /// Remove from the user-level config instead
+        #[arg(long)]
+        global: bool,
+    },
+
+    /// Check config files for unknown keys, wrong types and syntax errors
+    Validate {
+        /// Files to check (defaults to the global and current project's config)
+        files: Vec<PathBuf>,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
-pub enum RemoveMatchMode {
+pub enum MatchMode {
     /// Match by branch or directory name, and fail on ambiguity
     Auto,
     /// Match only by branch name
@@ -77,3 +303,100 @@ pub enum RemoveMatchMode {
     /// Match only by worktree directory name
     Dir,
 }
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum InitShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ListFormat {
+    /// Styled, aligned output
+    Human,
+    /// JSON array of worktree objects
+    Json,
+    /// One tab-separated line per worktree
+    Tsv,
+    /// NUL-terminated `key value` attributes, records separated by an empty attribute
+    Porcelain,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum StatusFormat {
+    /// Styled, labelled output
+    Human,
+    /// A single JSON object
+    Json,
+}
+
+/// Filters for `grov list`; a worktree is shown only if it passes all of them.
+#[derive(Args, Clone, Debug, Default)]
+pub struct ListFilters {
+    /// Only worktrees with uncommitted changes
+    #[arg(long)]
+    pub dirty: bool,
+
+    /// Only worktrees whose directory no longer exists
+    #[arg(long)]
+    pub missing: bool,
+
+    /// Only worktrees with commits their upstream does not have
+    #[arg(long)]
+    pub ahead: bool,
+
+    /// Only worktrees missing commits from their upstream
+    #[arg(long)]
+    pub behind: bool,
+
+    /// Only worktrees whose branch is merged into the default branch
+    #[arg(long)]
+    pub merged: bool,
+
+    /// Only worktrees whose last commit is older than this many days
+    #[arg(long, value_name = "DAYS")]
+    pub stale: Option<u64>,
+
+    /// Only worktrees whose branch matches this glob
+    #[arg(long, value_name = "GLOB")]
+    pub branch: Option<String>,
+}
+
+impl ListFilters {
+    pub fn is_empty(&self) -> bool {
+        !(self.dirty || self.missing || self.ahead || self.behind || self.merged)
+            && self.stale.is_none()
+            && self.branch.is_none()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ListColumn {
+    /// Subject of the last commit
+    Subject,
+    /// Age of the last commit
+    Date,
+    /// Author of the last commit
+    Author,
+    /// Upstream branch
+    Upstream,
+    /// `[gone]` when the upstream branch was deleted
+    Gone,
+    /// Number of stashes made on the branch
+    Stash,
+    /// Lock and prunable state
+    Lock,
+    /// Size of the worktree on disk
+    Size,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ListSort {
+    /// Branch name
+    Name,
+    /// Most recent commit first
+    Recent,
+    /// Dirty, missing and unknown worktrees first
+    Status,
+}
@@ -1,20 +1,31 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use console::style;
-use crossterm::event::{self, Event};
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Block, Paragraph};
 
-use crate::config::read_config;
+use crate::branch::{BranchPolicy, check_ref_format};
+use crate::cli::MatchMode;
+use crate::commands::remove::resolve_by_name;
+use crate::commands::shell_init::request_cd;
+use crate::config::{CollisionStrategy, GrovConfig, read_config};
+use crate::files::{CarryReport, carry_files};
 use crate::git::executor::run_git_ok;
+use crate::git::log::{self, LogLine, relative_age};
 use crate::git::repo::{current_branch, default_branch, find_bare_repo};
+use crate::git::status::ref_ahead_behind;
 use crate::git::worktree::{
-    add_worktree, available_branches, branch_exists_local, branch_exists_remote, list_worktrees,
+    BranchInfo, WorktreeInfo, add_worktree, available_branches, branch_exists_local,
+    branch_exists_remote, list_worktrees,
 };
-use crate::paths::{relative_from, worktree_dir};
+use crate::hooks::{Hook, HookContext, run_post_hook};
+use crate::paths::{relative_from, worktree_path};
 use crate::tui::FlowOutcome;
+use crate::tui::loader::Loader;
 use crate::tui::select_list::{self, SelectList, SelectResult};
 use crate::tui::terminal::run_tui;
 use crate::tui::text_input::{self, TextInput};
@@ -49,6 +60,24 @@ pub(crate) enum BranchChoice {
     New { name: String, base: String },
 }
 
+/// Commits shown in a branch preview; the ahead count covers the rest.
+const PREVIEW_COMMITS: usize = 100;
+
+/// Lines the preview moves per PageUp/PageDown.
+const PREVIEW_SCROLL_STEP: u16 = 5;
+
+/// Narrowest terminal that still gets the preview pane.
+const PREVIEW_MIN_WIDTH: u16 = 80;
+
+/// Commits of a branch that the default branch lacks.
+#[derive(Debug, Default)]
+struct BranchPreview {
+    commits: Vec<LogLine>,
+    /// Ahead/behind the base; `None` if either ref does not resolve.
+    ahead_behind: Option<(u32, u32)>,
+    error: Option<String>,
+}
+
 #[derive(Debug)]
 enum AddStep {
     SelectBranch,
@@ -58,15 +87,22 @@ enum AddStep {
 
 pub(crate) struct AddPicker {
     step: AddStep,
-    branches: Vec<String>,
+    branches: Vec<BranchInfo>,
     extras: Vec<String>,
     default_base: Option<String>,
     select: SelectList,
     input: TextInput,
+    policy: BranchPolicy,
+    /// Ref previews compare against, e.g. `origin/main`; no preview if `None`.
+    preview_base: Option<String>,
+    previews: HashMap<String, BranchPreview>,
+    /// Started on the first `update_preview` call.
+    loader: Option<Loader<BranchInfo, BranchPreview>>,
+    preview_scroll: u16,
 }
 
 impl AddPicker {
-    pub(crate) fn new(branches: Vec<String>, default_base: Option<String>) -> Self {
+    pub(crate) fn new(branches: Vec<BranchInfo>, default_base: Option<String>) -> Self {
         let step = if branches.is_empty() {
             AddStep::NewBranchName
         } else {
@@ -78,7 +114,7 @@ impl AddPicker {
         } else {
             vec!["Create a new branch".to_string()]
         };
-        let select = SelectList::new("Select a branch", branches.clone(), extras.clone());
+        let select = branch_select(&branches, extras.clone());
         let input = TextInput::new("New branch name");
 
         Self {
@@ -88,32 +124,124 @@ impl AddPicker {
             default_base,
             select,
             input,
+            policy: BranchPolicy::default(),
+            preview_base: None,
+            previews: HashMap::new(),
+            loader: None,
+            preview_scroll: 0,
+        }
+    }
+
+    /// Check new branch names against `policy` as they are typed.
+    pub(crate) fn with_policy(mut self, policy: BranchPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Show the commits of the highlighted branch that `base` lacks.
+    pub(crate) fn with_preview(mut self, base: String) -> Self {
+        self.preview_base = Some(base);
+        self
+    }
+
+    /// The highlighted branch, if its preview is shown but not loaded yet.
+    fn wanted_preview(&self) -> Option<&BranchInfo> {
+        self.preview_base.as_ref()?;
+        if !matches!(self.step, AddStep::SelectBranch) {
+            return None;
+        }
+        let branch = &self.branches[self.select.highlighted()?];
+        (!self.previews.contains_key(&branch.name)).then_some(branch)
+    }
+
+    /// Take in finished previews and ask for the highlighted branch's one
+    /// if it is not cached. Previews load on a background thread, so moving
+    /// through the list never waits on git.
+    pub(crate) fn update_preview(&mut self, repo: &Path) {
+        let Some(base) = &self.preview_base else {
+            return;
+        };
+        let loader = self.loader.get_or_insert_with(|| {
+            let (repo, base) = (repo.to_path_buf(), base.clone());
+            Loader::new(move |branch: &BranchInfo| load_branch_preview(&repo, &base, branch))
+        });
+        let finished = loader.finished();
+        let highlighted = self
+            .select
+            .highlighted()
+            .and_then(|i| self.branches.get(i))
+            .map(|b| b.name.clone());
+        for (branch, preview) in finished {
+            // Drop previews of branches the user has already moved past
+            if highlighted.as_ref() == Some(&branch.name) {
+                self.previews.insert(branch.name, preview);
+            }
+        }
+        if let Some(branch) = self.wanted_preview().cloned()
+            && let Some(loader) = &mut self.loader
+        {
+            loader.request(branch);
         }
     }
 
+    fn scroll_preview(&mut self, down: bool) {
+        let lines = self
+            .select
+            .highlighted()
+            .and_then(|i| self.previews.get(&self.branches[i].name))
+            .map_or(0, |preview| preview.commits.len() as u16);
+        self.preview_scroll = if down {
+            (self.preview_scroll + PREVIEW_SCROLL_STEP).min(lines.saturating_sub(1))
+        } else {
+            self.preview_scroll.saturating_sub(PREVIEW_SCROLL_STEP)
+        };
+    }
+
     pub(crate) fn handle_event(
         &mut self,
         event: &Event,
     ) -> anyhow::Result<FlowOutcome<BranchChoice>> {
+        if matches!(self.step, AddStep::SelectBranch)
+            && self.preview_base.is_some()
+            && let Event::Key(KeyEvent {
+                code: code @ (KeyCode::PageUp | KeyCode::PageDown),
+                ..
+            }) = event
+        {
+            self.scroll_preview(*code == KeyCode::PageDown);
+            return Ok(FlowOutcome::Continue);
+        }
+
+        let highlighted = self.select.highlighted();
+        let outcome = self.handle_step_event(event);
+        if self.select.highlighted() != highlighted {
+            self.preview_scroll = 0;
+        }
+        outcome
+    }
+
+    fn handle_step_event(&mut self, event: &Event) -> anyhow::Result<FlowOutcome<BranchChoice>> {
         match &self.step {
             AddStep::SelectBranch => match self.select.handle_event(event) {
                 select_list::Action::Selected(result) => match result {
                     SelectResult::Item(i) => {
                         return Ok(FlowOutcome::Done(BranchChoice::Existing(
-                            self.branches[i].clone(),
+                            self.branches[i].name.clone(),
                         )));
                     }
                     SelectResult::Extra(_) => {
                         self.input = TextInput::new("New branch name");
                         self.step = AddStep::NewBranchName;
                     }
+                    SelectResult::Items(_) => unreachable!(),
                 },
                 select_list::Action::Cancel => anyhow::bail!("cancelled"),
                 select_list::Action::Continue => {}
             },
             AddStep::NewBranchName => match self.input.handle_event(event) {
                 text_input::Action::Submit(name) => {
-                    if !name.is_empty() {
+                    self.input.error = self.policy.problem(&name).filter(|_| !name.is_empty());
+                    if !name.is_empty() && self.input.error.is_none() {
                         let base_default =
                             self.default_base.as_deref().unwrap_or("main").to_string();
                         let new_name = name.clone();
@@ -126,14 +254,13 @@ impl AddPicker {
                     if self.branches.is_empty() {
                         anyhow::bail!("cancelled");
                     }
-                    self.select = SelectList::new(
-                        "Select a branch",
-                        self.branches.clone(),
-                        self.extras.clone(),
-                    );
+                    self.select = branch_select(&self.branches, self.extras.clone());
                     self.step = AddStep::SelectBranch;
                 }
-                text_input::Action::Continue => {}
+                text_input::Action::Continue => {
+                    let name = &self.input.value;
+                    self.input.error = self.policy.problem(name).filter(|_| !name.is_empty());
+                }
             },
             AddStep::BaseBranch { new_name } => match self.input.handle_event(event) {
                 text_input::Action::Submit(base) => {
@@ -156,7 +283,15 @@ impl AddPicker {
         let area = frame.area();
         let widget_area = Rect::new(0, 1, area.width, area.height.saturating_sub(3));
 
+        let show_preview = self.preview_base.is_some() && area.width >= PREVIEW_MIN_WIDTH;
         match &self.step {
+            AddStep::SelectBranch if show_preview => {
+                let [list_area, preview_area] =
+                    Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+                        .areas(widget_area);
+                self.select.render(frame, list_area);
+                self.render_preview(frame, preview_area);
+            }
             AddStep::SelectBranch => {
                 self.select.render(frame, widget_area);
             }
@@ -177,6 +312,7 @@ impl AddPicker {
 
         // Help text
         let help = match &self.step {
+            AddStep::SelectBranch if show_preview => theme::HELP_SELECT_PREVIEW,
             AddStep::SelectBranch => theme::HELP_SELECT,
             AddStep::NewBranchName | AddStep::BaseBranch { .. } => theme::HELP_WIZARD,
         };
@@ -187,16 +323,134 @@ impl AddPicker {
             Rect::new(0, help_y, area.width, 1),
         );
     }
+
+    fn render_preview(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered()
+            .title(" Preview ")
+            .border_style(theme::DIM);
+        let (Some(i), Some(base)) = (self.select.highlighted(), &self.preview_base) else {
+            frame.render_widget(block, area);
+            return;
+        };
+        let branch = &self.branches[i];
+
+        let mut header = vec![Span::styled(branch.name.as_str(), theme::CYAN)];
+        let mut lines = Vec::new();
+        match self.previews.get(&branch.name) {
+            None => lines.push(Line::from(Span::styled("Loading…", theme::DIM))),
+            Some(preview) => {
+                if let Some((ahead, behind)) = preview.ahead_behind {
+                    header.push(Span::styled(format!("  ↑{ahead}"), theme::GREEN));
+                    header.push(Span::styled(format!(" ↓{behind}"), theme::ERROR));
+                    header.push(Span::styled(format!("  vs {base}"), theme::DIM));
+                }
+                if let Some(error) = &preview.error {
+                    lines.push(Line::from(Span::styled(error.as_str(), theme::ERROR)));
+                } else if preview.commits.is_empty() {
+                    lines.push(Line::from(Span::styled(
+                        format!("No commits beyond {base}"),
+                        theme::DIM,
+                    )));
+                }
+                let now = log::now();
+                for commit in &preview.commits {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{} ", commit.short_sha), theme::DIM),
+                        Span::raw(commit.subject.as_str()),
+                        Span::styled(format!("  {}", relative_age(commit.time, now)), theme::DIM),
+                    ]));
+                }
+                let shown = preview.commits.len() as u32;
+                if let Some((ahead, _)) = preview.ahead_behind
+                    && ahead > shown
+                {
+                    lines.push(Line::from(Span::styled(
+                        format!("… and {} more", ahead - shown),
+                        theme::DIM,
+                    )));
+                }
+            }
+        }
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let [header_area, _, body_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .areas(inner);
+        frame.render_widget(Paragraph::new(Line::from(header)), header_area);
+        frame.render_widget(
+            Paragraph::new(lines).scroll((self.preview_scroll, 0)),
+            body_area,
+        );
+    }
+}
+
+/// Commits of `branch` that `base` lacks, and how far apart they are.
+fn load_branch_preview(repo: &Path, base: &str, branch: &BranchInfo) -> BranchPreview {
+    // Remote-only branches have no local ref
+    let tip = if branch.local {
+        branch.name.clone()
+    } else {
+        format!("origin/{}", branch.name)
+    };
+    let mut preview = BranchPreview::default();
+    match log::commits_between(repo, base, &tip, PREVIEW_COMMITS) {
+        Ok(commits) => preview.commits = commits,
+        Err(err) => preview.error = Some(format!("{err:#}")),
+    }
+    preview.ahead_behind = ref_ahead_behind(repo, &tip, base).ok().flatten();
+    preview
+}
+
+/// The ref branch previews compare against: `origin/<default>` when it
+/// exists, else the local default branch. `None` if there is no default.
+pub(crate) fn preview_base(repo: &Path) -> Option<String> {
+    let default = default_branch(repo).ok()?;
+    Some(if branch_exists_remote(repo, &default) {
+        format!("origin/{default}")
+    } else {
+        default
+    })
+}
+
+/// Branch list with a local/remote marker and the tip's age after each name.
+fn branch_select(branches: &[BranchInfo], extras: Vec<String>) -> SelectList {
+    let now = log::now();
+    let names = branches.iter().map(|b| b.name.clone()).collect();
+    let hints = branches
+        .iter()
+        .map(|b| {
+            let marker = match (b.local, b.remote) {
+                (true, true) => "local+remote",
+                (true, false) => "local",
+                (false, _) => "remote",
+            };
+            vec![
+                Span::raw(format!("{marker:<12}")),
+                Span::styled(format!("  {}", relative_age(b.time, now)), theme::DIM),
+            ]
+        })
+        .collect();
+    SelectList::new("Select a branch", names, extras).with_hints(hints)
 }
 
 fn run_branch_picker(
-    branches: Vec<String>,
+    repo: &Path,
+    branches: Vec<BranchInfo>,
     default_base: Option<String>,
+    policy: BranchPolicy,
 ) -> anyhow::Result<BranchChoice> {
     run_tui(|terminal| {
-        let mut picker = AddPicker::new(branches, default_base);
+        let mut picker = AddPicker::new(branches, default_base).with_policy(policy);
+        if let Some(base) = preview_base(repo) {
+            picker = picker.with_preview(base);
+        }
 
         loop {
+            picker.update_preview(repo);
             terminal.draw(|frame| picker.render(frame))?;
 
             if event::poll(std::time::Duration::from_millis(100))? {
@@ -213,12 +467,19 @@ pub fn execute(
     branch: Option<&str>,
     base: Option<&str>,
     custom_path: Option<&Path>,
+    copy_from: Option<&str>,
+    no_hooks: bool,
 ) -> anyhow::Result<()> {
     use std::io::IsTerminal;
 
     let cwd = std::env::current_dir()?;
     let repo = find_bare_repo(&cwd)?;
-    let config = read_config(&repo);
+    let config = read_config(&repo)?;
+    let policy = BranchPolicy::from_config(&config.branch)?;
+    if let Some(name) = branch {
+        check_ref_format(name)?;
+    }
+    let copy_source = resolve_copy_source(&repo, &config, &cwd, copy_from)?;
 
     // Fetch latest
     if let Err(err) = run_git_ok(Some(&repo), &["fetch", "origin"]) {
@@ -240,35 +501,28 @@ pub fn execute(
             let branches = available_branches(&repo, &worktrees)?;
             let cur = current_branch(&cwd).unwrap_or(None);
 
-            match run_branch_picker(branches, cur)? {
+            match run_branch_picker(&repo, branches, cur, policy.clone())? {
                 BranchChoice::Existing(name) => name,
                 BranchChoice::New { name, base: b } => {
                     // Create new branch with base — handle inline
-                    let wt_path = match custom_path {
-                        Some(p) => p.to_path_buf(),
-                        None => worktree_dir(&repo, &name, &config.worktree.prefix),
-                    };
-                    if wt_path.exists() {
-                        anyhow::bail!("worktree directory already exists at {}", wt_path.display());
-                    }
+                    let wt_path = target_path(&repo, &config, &name, custom_path)?;
                     add_worktree(&repo, &wt_path, Some(&b), &["-b", &name])?;
-                    print_success(&name, &wt_path, &cwd);
-                    return Ok(());
+                    return finish_add(
+                        &repo,
+                        &config,
+                        &name,
+                        &wt_path,
+                        &cwd,
+                        copy_source.as_deref(),
+                        no_hooks,
+                    );
                 }
             }
         }
     };
 
-    // Determine worktree path
-    let wt_path = match custom_path {
-        Some(p) => p.to_path_buf(),
-        None => worktree_dir(&repo, &branch, &config.worktree.prefix),
-    };
-
-    // Check if worktree dir already exists
-    if wt_path.exists() {
-        anyhow::bail!("worktree directory already exists at {}", wt_path.display());
-    }
+    // Determine worktree path, refusing or renaming around existing dirs
+    let wt_path = target_path(&repo, &config, &branch, custom_path)?;
 
     let remote_ref = format!("origin/{branch}");
 
@@ -284,7 +538,8 @@ pub fn execute(
             &["--track", "-b", &branch],
         )?;
     } else {
-        // New branch — resolve base via flag, prompt, or non-TTY error
+        // New branch — check naming, then resolve base via flag, prompt, or non-TTY error
+        policy.check(&branch)?;
         let current = current_branch(&cwd).unwrap_or(None);
         let is_tty = std::io::stdin().is_terminal();
         let base_branch = match resolve_base_branch(base, current.as_deref(), is_tty) {
@@ -310,9 +565,220 @@ pub fn execute(
         add_worktree(&repo, &wt_path, Some(&base_branch), &["-b", &branch])?;
     }
 
-    print_success(&branch, &wt_path, &cwd);
+    finish_add(
+        &repo,
+        &config,
+        &branch,
+        &wt_path,
+        &cwd,
+        copy_source.as_deref(),
+        no_hooks,
+    )
+}
+
+/// Pick the worktree that `copy_files`/`link_files` are taken from: the one
+/// named by `--copy-from`, else the current one, else the default branch's.
+fn resolve_copy_source(
+    repo: &Path,
+    config: &GrovConfig,
+    cwd: &Path,
+    copy_from: Option<&str>,
+) -> anyhow::Result<Option<PathBuf>> {
+    let nothing_to_copy =
+        config.worktree.copy_files.is_empty() && config.worktree.link_files.is_empty();
+    if nothing_to_copy {
+        if copy_from.is_some() {
+            eprintln!(
+                "{} --copy-from has no effect: worktree.copy_files and worktree.link_files are empty",
+                style("warning:").yellow().bold()
+            );
+        }
+        return Ok(None);
+    }
+
+    let worktrees = list_worktrees(repo)?;
+    if let Some(name) = copy_from {
+        let wt = &worktrees[resolve_by_name(&worktrees, name, MatchMode::Auto)?];
+        if !wt.path.exists() {
+            anyhow::bail!("--copy-from worktree is missing: {}", wt.path.display());
+        }
+        return Ok(Some(wt.path.clone()));
+    }
 
-    Ok(())
+    let source = match current_worktree(&worktrees, cwd) {
+        Some(wt) => Some(wt),
+        None => {
+            let default = default_branch(repo).ok();
+            worktrees.iter().find(|wt| {
+                !wt.is_bare && wt.branch.is_some() && wt.branch == default && wt.path.exists()
+            })
+        }
+    };
+    Ok(source.map(|wt| wt.path.clone()))
+}
+
+/// The worktree `cwd` is in. The bare repo is skipped: it has no files to copy.
+fn current_worktree<'a>(worktrees: &'a [WorktreeInfo], cwd: &Path) -> Option<&'a WorktreeInfo> {
+    let cwd = std::fs::canonicalize(cwd).unwrap_or_else(|_| cwd.to_path_buf());
+    worktrees
+        .iter()
+        .filter(|wt| !wt.is_bare)
+        .find(|wt| std::fs::canonicalize(&wt.path).is_ok_and(|root| cwd.starts_with(root)))
+}
+
+/// The directory for the new worktree: `--path` as given, or the configured
+/// naming with `worktree.on_collision` applied.
+fn target_path(
+    repo: &Path,
+    config: &GrovConfig,
+    branch: &str,
+    custom_path: Option<&Path>,
+) -> anyhow::Result<PathBuf> {
+    if let Some(path) = custom_path {
+        if path.exists() {
+            anyhow::bail!("worktree directory already exists at {}", path.display());
+        }
+        return Ok(path.to_path_buf());
+    }
+    let path = worktree_path(repo, branch, &config.worktree)?;
+    claim_worktree_path(
+        &list_worktrees(repo)?,
+        branch,
+        path,
+        config.worktree.on_collision,
+    )
+}
+
+/// What already occupies the directory a new worktree would use.
+#[derive(Debug, PartialEq)]
+enum Occupant {
+    Worktree {
+        path: PathBuf,
+        branch: Option<String>,
+    },
+    Directory(PathBuf),
+}
+
+/// Find what is at `path`. Names are compared case-insensitively so that
+/// projects shared over case-insensitive filesystems stay consistent.
+fn occupant(worktrees: &[WorktreeInfo], path: &Path) -> Option<Occupant> {
+    let lower = |p: &Path| p.to_string_lossy().to_lowercase();
+    let target = lower(path);
+
+    if let Some(wt) = worktrees
+        .iter()
+        .find(|wt| !wt.is_bare && lower(&wt.path) == target)
+    {
+        return Some(Occupant::Worktree {
+            path: wt.path.clone(),
+            branch: wt.branch.clone(),
+        });
+    }
+    if path.exists() {
+        return Some(Occupant::Directory(path.to_path_buf()));
+    }
+    std::fs::read_dir(path.parent()?)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|p| lower(p) == target)
+        .map(Occupant::Directory)
+}
+
+/// Return `path` if it is free; otherwise explain who holds it and either
+/// fail or pick the alternative name `strategy` asks for.
+fn claim_worktree_path(
+    worktrees: &[WorktreeInfo],
+    branch: &str,
+    path: PathBuf,
+    strategy: CollisionStrategy,
+) -> anyhow::Result<PathBuf> {
+    let Some(taken) = occupant(worktrees, &path) else {
+        return Ok(path);
+    };
+    if let Occupant::Worktree {
+        path: existing,
+        branch: Some(owner),
+    } = &taken
+        && owner == branch
+    {
+        anyhow::bail!(
+            "branch {branch} already has a worktree at {}",
+            existing.display()
+        );
+    }
+
+    let conflict = describe_collision(branch, &path, &taken);
+    let alternative = match strategy {
+        CollisionStrategy::Error => None,
+        CollisionStrategy::Suffix => (2..)
+            .map(|n| with_suffix(&path, &n.to_string()))
+            .find(|p| occupant(worktrees, p).is_none()),
+        CollisionStrategy::Hash => Some(with_suffix(&path, &branch_hash(branch)))
+            .filter(|p| occupant(worktrees, p).is_none()),
+    };
+    match alternative {
+        Some(alternative) => {
+            eprintln!(
+                "{} {conflict}; using {}",
+                style("note:").cyan().bold(),
+                alternative.display()
+            );
+            Ok(alternative)
+        }
+        None if strategy == CollisionStrategy::Error => anyhow::bail!(
+            "{conflict}\n  set worktree.on_collision to \"suffix\" or \"hash\", or pass --path"
+        ),
+        None => anyhow::bail!(
+            "{conflict}, and {} is taken too",
+            with_suffix(&path, &branch_hash(branch)).display()
+        ),
+    }
+}
+
+fn describe_collision(branch: &str, path: &Path, taken: &Occupant) -> String {
+    let case_note = |other: &Path| {
+        if other != path {
+            " (names differ only in case)"
+        } else {
+            ""
+        }
+    };
+    match taken {
+        Occupant::Worktree {
+            path: other,
+            branch: Some(owner),
+        } => format!(
+            "{branch} maps to {}, which is already the worktree of branch {owner}{}",
+            path.display(),
+            case_note(other)
+        ),
+        Occupant::Worktree {
+            path: other,
+            branch: None,
+        } => format!(
+            "{branch} maps to {}, which is already a detached worktree{}",
+            path.display(),
+            case_note(other)
+        ),
+        Occupant::Directory(other) => format!(
+            "worktree directory already exists at {}{}",
+            other.display(),
+            case_note(other)
+        ),
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{name}-{suffix}"))
+}
+
+/// Short, stable hash of a branch name (FNV-1a), for `on_collision = "hash"`.
+fn branch_hash(branch: &str) -> String {
+    let hash = branch.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{:06x}", hash & 0x00ff_ffff)
 }
 
 fn run_base_branch_prompt(branch: &str, default: &str) -> anyhow::Result<String> {
@@ -349,7 +815,86 @@ fn run_base_branch_prompt(branch: &str, default: &str) -> anyhow::Result<String>
     })
 }
 
-fn print_success(branch: &str, wt_path: &Path, cwd: &Path) {
+/// Report the new worktree, carry over configured files, run `post_add`
+/// hooks, and hand the path to the shell wrapper.
+fn finish_add(
+    repo: &Path,
+    config: &GrovConfig,
+    branch: &str,
+    wt_path: &Path,
+    cwd: &Path,
+    copy_source: Option<&Path>,
+    no_hooks: bool,
+) -> anyhow::Result<()> {
+    let cd_requested = request_cd(wt_path)?;
+    print_success(branch, wt_path, cwd, cd_requested);
+
+    let worktree = &config.worktree;
+    if !worktree.copy_files.is_empty() || !worktree.link_files.is_empty() {
+        match copy_source {
+            Some(source) => {
+                // The worktree exists at this point, so report rather than fail
+                match carry_files(source, wt_path, &worktree.copy_files, &worktree.link_files) {
+                    Ok(report) => print_carry_report(source, &report),
+                    Err(err) => eprintln!(
+                        "{} could not copy files: {err:#}",
+                        style("warning:").yellow().bold()
+                    ),
+                }
+            }
+            None => eprintln!(
+                "{} no worktree to copy files from; pass --copy-from <name>",
+                style("warning:").yellow().bold()
+            ),
+        }
+    }
+
+    if !no_hooks {
+        let ctx = HookContext {
+            branch: Some(branch),
+            worktree: wt_path,
+            bare_repo: repo,
+        };
+        run_post_hook(&config.hooks, Hook::PostAdd, &ctx, wt_path);
+    }
+
+    Ok(())
+}
+
+fn print_carry_report(source: &Path, report: &CarryReport) {
+    if report.is_empty() {
+        return;
+    }
+    let source_name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| source.display().to_string());
+    println!("  Files from {}:", style(source_name).bold());
+    for path in &report.copied {
+        println!("    {}  {}", style("copied  ").green(), path.display());
+    }
+    for path in &report.linked {
+        println!("    {}  {}", style("linked  ").green(), path.display());
+    }
+    for path in &report.conflicts {
+        println!(
+            "    {}  {} {}",
+            style("conflict").yellow(),
+            path.display(),
+            style("(already exists, left untouched)").dim()
+        );
+    }
+    for pattern in &report.skipped {
+        println!(
+            "    {}  {} {}",
+            style("skipped ").dim(),
+            pattern,
+            style("(no match)").dim()
+        );
+    }
+}
+
+fn print_success(branch: &str, wt_path: &Path, cwd: &Path, cd_requested: bool) {
     println!(
         "{} Created worktree at {} on branch {}",
         style("\u{2713}").green().bold(),
@@ -357,6 +902,11 @@ fn print_success(branch: &str, wt_path: &Path, cwd: &Path) {
         style(branch).cyan().bold(),
     );
 
+    // The shell wrapper takes us there, no need for a hint
+    if cd_requested {
+        return;
+    }
+
     let rel = relative_from(wt_path, cwd);
     if rel != Path::new(".") {
         let display = rel.display().to_string();
@@ -390,6 +940,20 @@ mod tests {
         Ok(FlowOutcome::Continue)
     }
 
+    fn branches(names: &[&str]) -> Vec<BranchInfo> {
+        names
+            .iter()
+            .map(|name| BranchInfo {
+                name: name.to_string(),
+                local: true,
+                remote: false,
+                time: log::now(),
+                author: "Test".to_string(),
+                upstream: None,
+            })
+            .collect()
+    }
+
     #[test]
     fn resolve_base_provided() {
         assert_eq!(
@@ -444,7 +1008,7 @@ mod tests {
 
     #[test]
     fn select_existing_branch() {
-        let mut picker = AddPicker::new(vec!["main".into(), "develop".into()], Some("main".into()));
+        let mut picker = AddPicker::new(branches(&["main", "develop"]), Some("main".into()));
         // Press Enter on first item (main)
         let result = feed_picker(&mut picker, &[enter()]).unwrap();
         match result {
@@ -457,7 +1021,7 @@ mod tests {
 
     #[test]
     fn create_new_branch() {
-        let mut picker = AddPicker::new(vec!["main".into(), "develop".into()], Some("main".into()));
+        let mut picker = AddPicker::new(branches(&["main", "develop"]), Some("main".into()));
         let mut events: Vec<Event> = Vec::new();
         // Navigate down past items to "Create a new branch" extra option
         events.push(key_event(crossterm::event::KeyCode::Down)); // develop
@@ -486,7 +1050,7 @@ mod tests {
 
     #[test]
     fn esc_from_new_name_returns_to_list() {
-        let mut picker = AddPicker::new(vec!["main".into(), "develop".into()], Some("main".into()));
+        let mut picker = AddPicker::new(branches(&["main", "develop"]), Some("main".into()));
         let events = vec![
             key_event(crossterm::event::KeyCode::Down),
             key_event(crossterm::event::KeyCode::Down),
@@ -527,11 +1091,76 @@ mod tests {
     }
 
     #[test]
-    fn renders_branch_list() {
-        let picker = AddPicker::new(
-            vec!["main".into(), "develop".into(), "feature-y".into()],
-            Some("main".into()),
+    fn invalid_new_name_shows_error_and_blocks_submit() {
+        let policy = BranchPolicy::from_config(&crate::config::BranchConfig {
+            prefixes: vec!["feat/".into()],
+            pattern: String::new(),
+        })
+        .unwrap();
+        let mut picker = AddPicker::new(vec![], Some("main".into())).with_policy(policy);
+
+        let mut events = type_string("login");
+        events.push(enter());
+        let result = feed_picker(&mut picker, &events).unwrap();
+        assert!(matches!(result, FlowOutcome::Continue));
+        assert!(matches!(picker.step, AddStep::NewBranchName));
+        assert_eq!(picker.input.error.as_deref(), Some("must start with feat/"));
+
+        let mut terminal = test_terminal(80, 24);
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(
+            text.contains("must start with feat/"),
+            "expected inline error in: {text}"
         );
+
+        // Fixing the name clears the error and moves on
+        for _ in 0..5 {
+            feed_picker(
+                &mut picker,
+                &[key_event(crossterm::event::KeyCode::Backspace)],
+            )
+            .unwrap();
+        }
+        let mut events = type_string("feat/login");
+        events.push(enter());
+        events.push(enter());
+        let result = feed_picker(&mut picker, &events).unwrap();
+        assert!(matches!(
+            result,
+            FlowOutcome::Done(BranchChoice::New { ref name, .. }) if name == "feat/login"
+        ));
+    }
+
+    #[test]
+    fn ref_format_errors_appear_while_typing() {
+        let mut picker = AddPicker::new(vec![], Some("main".into()));
+        feed_picker(&mut picker, &type_string("a b")).unwrap();
+        assert_eq!(picker.input.error.as_deref(), Some("cannot contain spaces"));
+        feed_picker(
+            &mut picker,
+            &[key_event(crossterm::event::KeyCode::Backspace)],
+        )
+        .unwrap();
+        feed_picker(
+            &mut picker,
+            &[key_event(crossterm::event::KeyCode::Backspace)],
+        )
+        .unwrap();
+        assert_eq!(picker.input.error, None);
+    }
+
+    #[test]
+    fn renders_branch_list() {
+        let mut entries = branches(&["main", "develop", "feature-y"]);
+        entries[1].remote = true;
+        entries[2] = BranchInfo {
+            local: false,
+            remote: true,
+            time: log::now() - 2 * 86_400,
+            ..entries[2].clone()
+        };
+        let picker = AddPicker::new(entries, Some("main".into()));
         let mut terminal = test_terminal(80, 24);
         terminal.draw(|frame| picker.render(frame)).unwrap();
         let text = buffer_text(&terminal);
@@ -541,5 +1170,284 @@ mod tests {
             text.contains("feature-y"),
             "expected 'feature-y' in: {text}"
         );
+        assert!(
+            text.contains("main       local         just now"),
+            "expected marker and date in: {text}"
+        );
+        assert!(
+            text.contains("develop    local+remote  just now"),
+            "expected marker and date in: {text}"
+        );
+        assert!(
+            text.contains("feature-y  remote        2d ago"),
+            "expected marker and date in: {text}"
+        );
+    }
+
+    fn commit(sha: &str, subject: &str) -> LogLine {
+        LogLine {
+            short_sha: sha.to_string(),
+            subject: subject.to_string(),
+            time: log::now(),
+        }
+    }
+
+    fn previewing_picker() -> AddPicker {
+        let mut picker = AddPicker::new(branches(&["feat/login", "develop"]), None)
+            .with_preview("origin/main".into());
+        picker.previews.insert(
+            "feat/login".into(),
+            BranchPreview {
+                commits: vec![
+                    commit("abc1234", "Add login form"),
+                    commit("def5678", "Wire API"),
+                ],
+                ahead_behind: Some((102, 7)),
+                error: None,
+            },
+        );
+        picker
+    }
+
+    #[test]
+    fn wants_preview_of_highlighted_uncached_branch() {
+        let picker = AddPicker::new(branches(&["main"]), None);
+        assert_eq!(picker.wanted_preview(), None);
+
+        let mut picker = previewing_picker();
+        assert_eq!(picker.wanted_preview(), None); // feat/login is cached
+        feed_picker(&mut picker, &[key_event(KeyCode::Down)]).unwrap();
+        assert_eq!(
+            picker.wanted_preview().map(|b| b.name.as_str()),
+            Some("develop")
+        );
+        // Nothing to preview on the "Create a new branch" row
+        feed_picker(&mut picker, &[key_event(KeyCode::Down)]).unwrap();
+        assert_eq!(picker.wanted_preview(), None);
+    }
+
+    #[test]
+    fn loads_previews_in_background_and_drops_passed_branches() {
+        // Poll until the loader has answered every request so far
+        fn settle(picker: &mut AddPicker, repo: &Path) {
+            for _ in 0..500 {
+                picker.update_preview(repo);
+                if picker.loader.as_ref().is_some_and(|l| !l.is_loading()) {
+                    return;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(5));
+            }
+            panic!("preview never loaded");
+        }
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut picker = previewing_picker();
+
+        // Ask for develop, then move on before it arrives
+        feed_picker(&mut picker, &[key_event(KeyCode::Down)]).unwrap();
+        picker.update_preview(tmp.path());
+        feed_picker(&mut picker, &[key_event(KeyCode::Down)]).unwrap();
+        settle(&mut picker, tmp.path());
+        assert!(!picker.previews.contains_key("develop"));
+
+        feed_picker(&mut picker, &[key_event(KeyCode::Up)]).unwrap();
+        picker.update_preview(tmp.path());
+        settle(&mut picker, tmp.path());
+        // Not a repository, but the preview still arrives with the error
+        assert!(picker.previews["develop"].error.is_some());
+    }
+
+    #[test]
+    fn renders_preview_beside_branch_list() {
+        let picker = previewing_picker();
+        let mut terminal = test_terminal(100, 24);
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Preview"), "expected pane in: {text}");
+        assert!(
+            text.contains("feat/login  ↑102 ↓7  vs origin/main"),
+            "expected ahead/behind in: {text}"
+        );
+        assert!(
+            text.contains("abc1234 Add login form  just now"),
+            "expected commit in: {text}"
+        );
+        assert!(text.contains("… and 100 more"), "expected rest in: {text}");
+        assert!(text.contains("PgUp/PgDn"), "expected help in: {text}");
+
+        // Too narrow for the split: the list keeps the whole width
+        let mut terminal = test_terminal(60, 24);
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        assert!(!buffer_text(&terminal).contains("Preview"));
+    }
+
+    #[test]
+    fn renders_loading_and_empty_previews() {
+        let mut picker = previewing_picker();
+        feed_picker(&mut picker, &[key_event(KeyCode::Down)]).unwrap();
+        let mut terminal = test_terminal(100, 24);
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        assert!(buffer_text(&terminal).contains("Loading…"));
+
+        picker.previews.insert(
+            "develop".into(),
+            BranchPreview {
+                ahead_behind: Some((0, 3)),
+                ..BranchPreview::default()
+            },
+        );
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(
+            text.contains("No commits beyond origin/main"),
+            "expected empty note in: {text}"
+        );
+    }
+
+    #[test]
+    fn page_keys_scroll_preview_and_moving_resets_it() {
+        let mut picker = previewing_picker();
+        feed_picker(&mut picker, &[key_event(KeyCode::PageDown)]).unwrap();
+        // Clamped to the last of the two commits
+        assert_eq!(picker.preview_scroll, 1);
+        feed_picker(&mut picker, &[key_event(KeyCode::PageUp)]).unwrap();
+        assert_eq!(picker.preview_scroll, 0);
+
+        feed_picker(&mut picker, &[key_event(KeyCode::PageDown)]).unwrap();
+        feed_picker(&mut picker, &[key_event(KeyCode::Down)]).unwrap();
+        assert_eq!(picker.preview_scroll, 0);
+    }
+
+    fn worktree(path: &str, branch: Option<&str>) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from(path),
+            head: "abc1234".to_string(),
+            branch: branch.map(str::to_string),
+            is_bare: false,
+            locked: None,
+            prunable: None,
+        }
+    }
+
+    #[test]
+    fn current_worktree_skips_bare_repo() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let bare = tmp.path().join("p.git");
+        let main = tmp.path().join("p_main");
+        std::fs::create_dir_all(bare.join("refs")).unwrap();
+        std::fs::create_dir_all(main.join("src")).unwrap();
+        let worktrees = vec![
+            WorktreeInfo {
+                is_bare: true,
+                ..worktree(bare.to_str().unwrap(), None)
+            },
+            worktree(main.to_str().unwrap(), Some("main")),
+        ];
+
+        assert!(current_worktree(&worktrees, &bare.join("refs")).is_none());
+        assert_eq!(
+            current_worktree(&worktrees, &main.join("src")).map(|wt| &wt.path),
+            Some(&main)
+        );
+    }
+
+    #[test]
+    fn claim_free_path() {
+        let worktrees = vec![worktree("/nonexistent/p_main", Some("main"))];
+        let path = PathBuf::from("/nonexistent/p_feature-login");
+        assert_eq!(
+            claim_worktree_path(
+                &worktrees,
+                "feature/login",
+                path.clone(),
+                CollisionStrategy::Error
+            )
+            .unwrap(),
+            path
+        );
+    }
+
+    #[test]
+    fn claim_explains_collision_with_other_branch() {
+        let worktrees = vec![worktree(
+            "/nonexistent/p_feature-login",
+            Some("feature-login"),
+        )];
+        let err = claim_worktree_path(
+            &worktrees,
+            "feature/login",
+            PathBuf::from("/nonexistent/p_feature-login"),
+            CollisionStrategy::Error,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.contains("which is already the worktree of branch feature-login"),
+            "{err}"
+        );
+        assert!(err.contains("worktree.on_collision"), "{err}");
+    }
+
+    #[test]
+    fn claim_detects_case_insensitive_collision() {
+        let worktrees = vec![worktree("/nonexistent/p_Feature", Some("Feature"))];
+        let err = claim_worktree_path(
+            &worktrees,
+            "feature",
+            PathBuf::from("/nonexistent/p_feature"),
+            CollisionStrategy::Error,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("names differ only in case"), "{err}");
+    }
+
+    #[test]
+    fn claim_suffixes_deterministically() {
+        let worktrees = vec![
+            worktree("/nonexistent/p_a-b", Some("a-b")),
+            worktree("/nonexistent/p_a-b-2", Some("a//b")),
+        ];
+        assert_eq!(
+            claim_worktree_path(
+                &worktrees,
+                "a/b",
+                PathBuf::from("/nonexistent/p_a-b"),
+                CollisionStrategy::Suffix
+            )
+            .unwrap(),
+            PathBuf::from("/nonexistent/p_a-b-3")
+        );
+    }
+
+    #[test]
+    fn claim_hash_depends_only_on_branch() {
+        let worktrees = vec![worktree("/nonexistent/p_a-b", Some("a-b"))];
+        let path = claim_worktree_path(
+            &worktrees,
+            "a/b",
+            PathBuf::from("/nonexistent/p_a-b"),
+            CollisionStrategy::Hash,
+        )
+        .unwrap();
+        assert_eq!(
+            path,
+            PathBuf::from(format!("/nonexistent/p_a-b-{}", branch_hash("a/b")))
+        );
+        assert_ne!(branch_hash("a/b"), branch_hash("a-b"));
+        assert_eq!(branch_hash("a/b").len(), 6);
+    }
+
+    #[test]
+    fn claim_same_branch_reports_existing_worktree() {
+        let worktrees = vec![worktree("/nonexistent/p_main", Some("main"))];
+        let err = claim_worktree_path(
+            &worktrees,
+            "main",
+            PathBuf::from("/nonexistent/p_main"),
+            CollisionStrategy::Suffix,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("branch main already has a worktree"), "{err}");
     }
 }
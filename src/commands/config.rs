@@ -0,0 +1,160 @@
+use std::path::{Path, PathBuf};
+
+use console::style;
+
+use toml::Value;
+
+use crate::config::{
+    KEYS, edit_config_file, env_var_name, global_config_path, key_kind, load_config,
+    load_global_config, parse_cli_value, repo_config_path, validate_file,
+};
+use crate::git::repo::find_bare_repo;
+
+pub fn execute_show(origin: bool) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    // Outside a project only the global file and environment apply
+    let repo = find_bare_repo(&cwd).ok();
+    let loaded = load_config(repo.as_deref())?;
+
+    for &(key, kind) in KEYS {
+        let value = loaded.value(key, kind);
+        if origin {
+            println!("{}\t{key} = {value}", loaded.origin(key));
+        } else {
+            println!("{key} = {value}");
+        }
+    }
+
+    Ok(())
+}
+
+pub fn execute_get(key: &str, global: bool) -> anyhow::Result<()> {
+    let kind = key_kind(key)?;
+    let loaded = if global {
+        load_global_config()?
+    } else {
+        let cwd = std::env::current_dir()?;
+        load_config(find_bare_repo(&cwd).ok().as_deref())?
+    };
+
+    // Raw values, one list item per line, so scripts need no TOML parsing
+    match loaded.value(key, kind) {
+        Value::String(s) => println!("{s}"),
+        Value::Array(items) => {
+            for item in items {
+                if let Value::String(s) = item {
+                    println!("{s}");
+                }
+            }
+        }
+        other => println!("{other}"),
+    }
+    Ok(())
+}
+
+pub fn execute_set(key: &str, values: &[String], global: bool) -> anyhow::Result<()> {
+    let kind = key_kind(key)?;
+    let value = parse_cli_value(key, kind, values)?;
+    let path = target_file(global)?;
+
+    edit_config_file(&path, key, Some(&value))?;
+    println!(
+        "{} Set {key} = {value} in {}",
+        style("\u{2713}").green().bold(),
+        path.display()
+    );
+    warn_if_overridden(key);
+    Ok(())
+}
+
+pub fn execute_unset(key: &str, global: bool) -> anyhow::Result<()> {
+    key_kind(key)?;
+    let path = target_file(global)?;
+
+    if edit_config_file(&path, key, None)? {
+        println!(
+            "{} Unset {key} in {}",
+            style("\u{2713}").green().bold(),
+            path.display()
+        );
+        warn_if_overridden(key);
+    } else {
+        println!(
+            "{}",
+            style(format!("{key} is not set in {}", path.display())).dim()
+        );
+    }
+    Ok(())
+}
+
+/// The file `set`/`unset` edit: the user-level config or the project's.
+fn target_file(global: bool) -> anyhow::Result<PathBuf> {
+    if global {
+        return global_config_path()
+            .ok_or_else(|| anyhow::anyhow!("cannot locate the user config: HOME is not set"));
+    }
+    let cwd = std::env::current_dir()?;
+    Ok(repo_config_path(&find_bare_repo(&cwd)?))
+}
+
+fn warn_if_overridden(key: &str) {
+    let var = env_var_name(key);
+    if std::env::var_os(&var).is_some() {
+        eprintln!(
+            "{} {var} is set and overrides this value",
+            style("warning:").yellow().bold()
+        );
+    }
+}
+
+pub fn execute_validate(files: &[PathBuf]) -> anyhow::Result<()> {
+    let files = if files.is_empty() {
+        default_config_files()?
+    } else {
+        for file in files {
+            if !file.is_file() {
+                anyhow::bail!("no such file: {}", file.display());
+            }
+        }
+        files.to_vec()
+    };
+
+    if files.is_empty() {
+        println!("{}", style("No config files found.").dim());
+        return Ok(());
+    }
+
+    let mut problems = 0;
+    for file in &files {
+        match validate_file(file) {
+            Ok(()) => println!("{} {}", style("\u{2713}").green().bold(), file.display()),
+            Err(errors) => {
+                problems += errors.len();
+                for e in errors {
+                    eprintln!("{} {e}", style("error:").red().bold());
+                }
+            }
+        }
+    }
+
+    if problems > 0 {
+        anyhow::bail!(
+            "found {problems} problem{}",
+            if problems == 1 { "" } else { "s" }
+        );
+    }
+    Ok(())
+}
+
+/// The global config and the current project's `.grov.toml`, where they exist.
+fn default_config_files() -> anyhow::Result<Vec<PathBuf>> {
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd).ok();
+    Ok(
+        [global_config_path(), repo.as_deref().map(repo_config_path)]
+            .into_iter()
+            .flatten()
+            .filter(|path: &PathBuf| Path::is_file(path))
+            .collect(),
+    )
+}
@@ -0,0 +1,277 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use console::style;
+use glob::Pattern;
+
+use crate::git::repo::find_bare_repo;
+use crate::git::status::is_dirty;
+use crate::git::worktree::{WorktreeInfo, list_worktrees, worktree_dir_name};
+
+/// Result of running the command in one worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Success,
+    Failed(Option<i32>),
+    SpawnError(String),
+}
+
+struct Target {
+    label: String,
+    worktree: WorktreeInfo,
+}
+
+pub fn execute(
+    dirty: bool,
+    clean: bool,
+    branch: Option<&str>,
+    jobs: usize,
+    command: &[String],
+) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let worktrees = list_worktrees(&repo)?;
+
+    let pattern = branch
+        .map(Pattern::new)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("invalid --branch glob: {e}"))?;
+
+    let targets: Vec<Target> = worktrees
+        .into_iter()
+        .filter(|wt| !wt.is_bare && wt.path.exists())
+        .filter(|wt| matches_branch_glob(wt, pattern.as_ref()))
+        .filter(|wt| {
+            if !dirty && !clean {
+                return true;
+            }
+            // Worktrees whose state cannot be read match neither filter
+            is_dirty(&wt.path).is_ok_and(|d| d == dirty)
+        })
+        .map(|wt| Target {
+            label: wt.branch.clone().unwrap_or_else(|| worktree_dir_name(&wt)),
+            worktree: wt,
+        })
+        .collect();
+
+    if targets.is_empty() {
+        println!("{}", style("No matching worktrees.").dim());
+        return Ok(());
+    }
+
+    let outcomes = if jobs <= 1 {
+        targets
+            .iter()
+            .map(|target| {
+                println!(
+                    "{} {} {}",
+                    style("\u{25b6}").cyan().bold(),
+                    style(&target.label).cyan().bold(),
+                    style(format!("({})", worktree_dir_name(&target.worktree))).dim(),
+                );
+                let outcome = run_inherited(target, command);
+                println!();
+                outcome
+            })
+            .collect()
+    } else {
+        run_parallel(&targets, command, jobs)
+    };
+
+    print_summary(&targets, &outcomes);
+
+    let failed = outcomes.iter().filter(|o| **o != Outcome::Success).count();
+    if failed > 0 {
+        anyhow::bail!(
+            "command failed in {failed} of {} worktree{}",
+            targets.len(),
+            if targets.len() == 1 { "" } else { "s" }
+        );
+    }
+    Ok(())
+}
+
+fn matches_branch_glob(worktree: &WorktreeInfo, pattern: Option<&Pattern>) -> bool {
+    match pattern {
+        None => true,
+        Some(pattern) => worktree
+            .branch
+            .as_deref()
+            .is_some_and(|branch| pattern.matches(branch)),
+    }
+}
+
+/// Run with the terminal attached, for sequential mode.
+fn run_inherited(target: &Target, command: &[String]) -> Outcome {
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .current_dir(&target.worktree.path)
+        .status();
+    match status {
+        Ok(status) if status.success() => Outcome::Success,
+        Ok(status) => Outcome::Failed(status.code()),
+        Err(e) => {
+            eprintln!(
+                "{} could not run {}: {e}",
+                style("error:").red().bold(),
+                command[0]
+            );
+            Outcome::SpawnError(e.to_string())
+        }
+    }
+}
+
+/// Run across a bounded pool of threads, prefixing every output line with
+/// the worktree label so concurrent output stays readable.
+fn run_parallel(targets: &[Target], command: &[String], jobs: usize) -> Vec<Outcome> {
+    let width = targets.iter().map(|t| t.label.len()).max().unwrap_or(0);
+    let next = AtomicUsize::new(0);
+    let outcomes: Vec<Mutex<Option<Outcome>>> = targets.iter().map(|_| Mutex::new(None)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(targets.len()) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(target) = targets.get(i) else {
+                        break;
+                    };
+                    let prefix = style(format!("[{:<width$}]", target.label))
+                        .cyan()
+                        .to_string();
+                    let outcome = run_prefixed(target, command, &prefix);
+                    *outcomes[i].lock().expect("outcome lock poisoned") = Some(outcome);
+                }
+            });
+        }
+    });
+
+    outcomes
+        .into_iter()
+        .map(|o| {
+            o.into_inner()
+                .expect("outcome lock poisoned")
+                .expect("every target is run")
+        })
+        .collect()
+}
+
+fn run_prefixed(target: &Target, command: &[String], prefix: &str) -> Outcome {
+    let child = Command::new(&command[0])
+        .args(&command[1..])
+        .current_dir(&target.worktree.path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("{prefix} could not run {}: {e}", command[0]);
+            return Outcome::SpawnError(e.to_string());
+        }
+    };
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let stderr = child.stderr.take().expect("stderr is piped");
+    std::thread::scope(|scope| {
+        scope.spawn(|| forward_lines(stderr, prefix, true));
+        forward_lines(stdout, prefix, false);
+    });
+
+    match child.wait() {
+        Ok(status) if status.success() => Outcome::Success,
+        Ok(status) => Outcome::Failed(status.code()),
+        Err(e) => Outcome::SpawnError(e.to_string()),
+    }
+}
+
+/// Copy `reader` line by line, writing each whole line under the stream lock
+/// so lines from different worktrees never interleave mid-line.
+fn forward_lines(reader: impl Read, prefix: &str, to_stderr: bool) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                let line = String::from_utf8_lossy(&buf);
+                let line = line.trim_end_matches(['\n', '\r']);
+                let _ = if to_stderr {
+                    writeln!(std::io::stderr().lock(), "{prefix} {line}")
+                } else {
+                    writeln!(std::io::stdout().lock(), "{prefix} {line}")
+                };
+            }
+        }
+    }
+}
+
+fn print_summary(targets: &[Target], outcomes: &[Outcome]) {
+    let width = targets.iter().map(|t| t.label.len()).max().unwrap_or(0);
+
+    println!();
+    for (target, outcome) in targets.iter().zip(outcomes) {
+        let (marker, detail) = match outcome {
+            Outcome::Success => (style("\u{2713}").green().bold().to_string(), String::new()),
+            Outcome::Failed(Some(code)) => (
+                style("\u{2717}").red().bold().to_string(),
+                style(format!("  exit {code}")).red().to_string(),
+            ),
+            Outcome::Failed(None) => (
+                style("\u{2717}").red().bold().to_string(),
+                style("  killed by signal").red().to_string(),
+            ),
+            Outcome::SpawnError(e) => (
+                style("\u{2717}").red().bold().to_string(),
+                style(format!("  {e}")).red().to_string(),
+            ),
+        };
+        println!(
+            "  {marker} {:<width$}  {}{detail}",
+            target.label,
+            style(format!("({})", worktree_dir_name(&target.worktree))).dim(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn worktree(branch: Option<&str>) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from("/project/wt"),
+            head: "abc1234".to_string(),
+            branch: branch.map(str::to_string),
+            is_bare: false,
+            locked: None,
+            prunable: None,
+        }
+    }
+
+    #[test]
+    fn branch_glob_matches_nested_names() {
+        let pattern = Pattern::new("feature/*").unwrap();
+        assert!(matches_branch_glob(
+            &worktree(Some("feature/login")),
+            Some(&pattern)
+        ));
+        assert!(!matches_branch_glob(
+            &worktree(Some("main")),
+            Some(&pattern)
+        ));
+    }
+
+    #[test]
+    fn branch_glob_skips_detached() {
+        let pattern = Pattern::new("*").unwrap();
+        assert!(!matches_branch_glob(&worktree(None), Some(&pattern)));
+        assert!(matches_branch_glob(&worktree(None), None));
+    }
+}
@@ -7,10 +7,12 @@ use ratatui::layout::Rect;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::config::{GrovConfig, WorktreeConfig, write_config};
+use crate::commands::shell_init::request_cd;
+use crate::config::{GrovConfig, WorktreeConfig, load_config, read_config, write_config};
 use crate::git::executor::run_git_ok;
 use crate::git::worktree::add_worktree;
-use crate::paths::{relative_from, repo_name_from_url, worktree_dir};
+use crate::hooks::{Hook, HookContext, run_post_hook};
+use crate::paths::{relative_from, repo_name_from_url, worktree_path};
 use crate::tui::FlowOutcome;
 use crate::tui::confirm::{self, Confirm};
 use crate::tui::step_bar::StepBar;
@@ -68,6 +70,22 @@ impl InitWizard {
         wizard
     }
 
+    /// Pre-fill the wizard with values supplied on the command line.
+    pub(crate) fn with_values(
+        mut self,
+        url: Option<&str>,
+        name: Option<&str>,
+        prefix: Option<&str>,
+        branch: Option<&str>,
+    ) -> Self {
+        self.url = url.unwrap_or_default().to_string();
+        self.name = name.unwrap_or_default().to_string();
+        self.prefix = prefix.unwrap_or_default().to_string();
+        self.branch = branch.unwrap_or_default().to_string();
+        self.setup_step();
+        self
+    }
+
     fn setup_step(&mut self) {
         match self.step {
             0 => {
@@ -243,9 +261,54 @@ impl InitWizard {
     }
 }
 
-fn run_wizard() -> anyhow::Result<WizardResult> {
+#[derive(Debug, PartialEq)]
+enum InitAction {
+    Run(WizardResult),
+    Wizard,
+    ErrorNotTty,
+}
+
+/// Decide whether init can run straight from the flags or needs the wizard.
+///
+/// All four values skip the wizard. Without a TTY only `url` is required:
+/// the name is derived from the URL, the prefix defaults to blank, and the
+/// branch falls back to `detect_branch` (then `main`).
+fn resolve_init_action(
+    url: Option<&str>,
+    name: Option<&str>,
+    prefix: Option<&str>,
+    branch: Option<&str>,
+    is_tty: bool,
+    detect_branch: impl FnOnce(&str) -> Option<String>,
+) -> InitAction {
+    if is_tty && (url.is_none() || name.is_none() || prefix.is_none() || branch.is_none()) {
+        return InitAction::Wizard;
+    }
+    let Some(url) = url.filter(|u| !u.is_empty()) else {
+        return InitAction::ErrorNotTty;
+    };
+    let branch = match branch {
+        Some(b) => b.to_string(),
+        None => detect_branch(url).unwrap_or_else(|| "main".to_string()),
+    };
+    InitAction::Run(WizardResult {
+        url: url.to_string(),
+        name: name
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| repo_name_from_url(url)),
+        prefix: prefix.unwrap_or_default().to_string(),
+        branch,
+    })
+}
+
+fn run_wizard(
+    url: Option<&str>,
+    name: Option<&str>,
+    prefix: Option<&str>,
+    branch: Option<&str>,
+) -> anyhow::Result<WizardResult> {
     run_tui(|terminal| {
-        let mut wizard = InitWizard::new();
+        let mut wizard = InitWizard::new().with_values(url, name, prefix, branch);
 
         loop {
             terminal.draw(|frame| wizard.render(frame))?;
@@ -260,14 +323,38 @@ fn run_wizard() -> anyhow::Result<WizardResult> {
     })
 }
 
-pub fn execute(path: Option<&Path>) -> anyhow::Result<()> {
-    let result = run_wizard()?;
+pub fn execute(
+    url: Option<&str>,
+    name: Option<&str>,
+    prefix: Option<&str>,
+    branch: Option<&str>,
+    path: Option<&Path>,
+    no_hooks: bool,
+) -> anyhow::Result<()> {
+    use std::io::IsTerminal;
+
+    let is_tty = std::io::stdin().is_terminal();
+    let result = match resolve_init_action(
+        url,
+        name,
+        prefix,
+        branch,
+        is_tty,
+        detect_default_branch_remote,
+    ) {
+        InitAction::Run(result) => result,
+        InitAction::Wizard => run_wizard(url, name, prefix, branch)?,
+        InitAction::ErrorNotTty => {
+            anyhow::bail!("--url is required when stdin is not a terminal");
+        }
+    };
     execute_clone_and_setup(
         &result.url,
         &result.name,
         &result.prefix,
         &result.branch,
         path,
+        no_hooks,
     )
 }
 
@@ -277,6 +364,7 @@ fn execute_clone_and_setup(
     prefix: &str,
     branch: &str,
     path: Option<&Path>,
+    no_hooks: bool,
 ) -> anyhow::Result<()> {
     let parent = match path {
         Some(p) => p.to_path_buf(),
@@ -287,6 +375,9 @@ fn execute_clone_and_setup(
     if project_dir.exists() {
         anyhow::bail!("directory already exists: {}", project_dir.display());
     }
+
+    // Surface a broken global config before cloning rather than after
+    load_config(None)?;
     std::fs::create_dir_all(&project_dir)?;
 
     let bare_path = project_dir.join("repo.git");
@@ -296,7 +387,9 @@ fn execute_clone_and_setup(
     let config = GrovConfig {
         worktree: WorktreeConfig {
             prefix: prefix.to_string(),
+            ..Default::default()
         },
+        ..Default::default()
     };
     write_config(&bare_path, &config)?;
 
@@ -311,7 +404,9 @@ fn execute_clone_and_setup(
 
     run_git_ok(Some(&bare_path), &["fetch", "origin"])?;
 
-    let wt_path = worktree_dir(&bare_path, branch, prefix);
+    // Read back so a path template from the global config applies
+    let config = read_config(&bare_path)?;
+    let wt_path = worktree_path(&bare_path, branch, &config.worktree)?;
     add_worktree(&bare_path, &wt_path, Some(branch), &[])?;
 
     println!(
@@ -321,15 +416,28 @@ fn execute_clone_and_setup(
         "bare repo",
         style(format!("{}/repo.git", project_name)).dim(),
         "worktree",
-        style(
-            wt_path
-                .file_name()
-                .map(|n| format!("{}/{}", project_name, n.to_string_lossy()))
-                .expect("worktree path must have a file name")
-        )
+        style(format!(
+            "{}/{}",
+            project_name,
+            relative_from(&wt_path, &project_dir).display()
+        ))
         .dim(),
     );
 
+    if !no_hooks {
+        let ctx = HookContext {
+            branch: Some(branch),
+            worktree: &wt_path,
+            bare_repo: &bare_path,
+        };
+        run_post_hook(&config.hooks, Hook::PostInit, &ctx, &wt_path);
+    }
+
+    // The shell wrapper takes us into the worktree, no need for hints
+    if request_cd(&wt_path)? {
+        return Ok(());
+    }
+
     let cwd = std::env::current_dir()?;
     let project_rel = relative_from(&project_dir, &cwd);
     if project_rel != Path::new(".") {
@@ -385,6 +493,97 @@ mod tests {
         w
     }
 
+    #[test]
+    fn resolve_all_flags_skips_wizard() {
+        let action = resolve_init_action(
+            Some("https://example.com/repo.git"),
+            Some("proj"),
+            Some("p"),
+            Some("develop"),
+            true,
+            |_| panic!("detection should not run"),
+        );
+        assert_eq!(
+            action,
+            InitAction::Run(WizardResult {
+                url: "https://example.com/repo.git".to_string(),
+                name: "proj".to_string(),
+                prefix: "p".to_string(),
+                branch: "develop".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn resolve_partial_flags_tty_uses_wizard() {
+        let action = resolve_init_action(
+            Some("https://example.com/repo.git"),
+            None,
+            Some("p"),
+            None,
+            true,
+            |_| None,
+        );
+        assert_eq!(action, InitAction::Wizard);
+    }
+
+    #[test]
+    fn resolve_non_tty_fills_defaults() {
+        let action = resolve_init_action(
+            Some("https://example.com/repo.git"),
+            None,
+            None,
+            None,
+            false,
+            |_| Some("trunk".to_string()),
+        );
+        assert_eq!(
+            action,
+            InitAction::Run(WizardResult {
+                url: "https://example.com/repo.git".to_string(),
+                name: "repo".to_string(),
+                prefix: String::new(),
+                branch: "trunk".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn resolve_non_tty_falls_back_to_main() {
+        let action = resolve_init_action(Some("/tmp/repo.git"), None, None, None, false, |_| None);
+        assert!(matches!(action, InitAction::Run(r) if r.branch == "main"));
+    }
+
+    #[test]
+    fn resolve_non_tty_without_url() {
+        let action = resolve_init_action(None, Some("proj"), None, None, false, |_| None);
+        assert_eq!(action, InitAction::ErrorNotTty);
+    }
+
+    #[test]
+    fn wizard_prefilled_values() {
+        let mut wizard = new_test_wizard().with_values(
+            Some("https://example.com/repo"),
+            Some("proj"),
+            Some("pp"),
+            Some("develop"),
+        );
+
+        // Accept every pre-filled value, then confirm
+        let events = vec![enter(), enter(), enter(), enter(), key_char('y')];
+
+        let result = feed_wizard(&mut wizard, &events).unwrap();
+        match result {
+            FlowOutcome::Done(r) => {
+                assert_eq!(r.url, "https://example.com/repo");
+                assert_eq!(r.name, "proj");
+                assert_eq!(r.prefix, "pp");
+                assert_eq!(r.branch, "develop");
+            }
+            FlowOutcome::Continue => panic!("expected Done"),
+        }
+    }
+
     #[test]
     fn wizard_full_flow() {
         let mut wizard = new_test_wizard();
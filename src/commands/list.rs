@@ -1,25 +1,105 @@
-use console::style;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
-use crate::git::repo::find_bare_repo;
-use crate::git::status;
-use crate::git::worktree::list_worktrees;
+use anyhow::{Context, anyhow};
+use clap::ValueEnum;
+use console::{Alignment, measure_text_width, pad_str, style, truncate_str};
+use serde::Serialize;
 
-enum WorktreeStatus {
+use crate::cli::{ListColumn, ListFilters, ListFormat, ListSort};
+use crate::config::{UntrackedFiles, read_config};
+use crate::git::log::{self, CommitInfo};
+use crate::git::repo::{default_branch, find_bare_repo};
+use crate::git::status::{self, StatusSummary};
+use crate::git::worktree::{WorktreeInfo, list_worktrees, merged_into_default, worktree_dir_name};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorktreeStatus {
     Clean,
     Dirty,
     Missing,
     Unknown,
 }
 
-pub fn execute(compact: bool) -> anyhow::Result<()> {
+impl WorktreeStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            WorktreeStatus::Clean => "clean",
+            WorktreeStatus::Dirty => "dirty",
+            WorktreeStatus::Missing => "missing",
+            WorktreeStatus::Unknown => "unknown",
+        }
+    }
+
+    /// Symbol and name, as shown to people.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            WorktreeStatus::Clean => "✓ clean",
+            WorktreeStatus::Dirty => "✦ dirty",
+            WorktreeStatus::Missing => "! missing",
+            WorktreeStatus::Unknown => "? unknown",
+        }
+    }
+}
+
+/// A worktree with its computed state, as shown by `grov list`.
+///
+/// This is the schema of `--format json`; keep field names stable.
+#[derive(Debug, Serialize)]
+pub struct ListEntry {
+    #[serde(flatten)]
+    pub worktree: WorktreeInfo,
+    pub dir_name: String,
+    pub is_detached: bool,
+    pub is_current: bool,
+    /// `None` for the bare repository entry.
+    pub status: Option<WorktreeStatus>,
+    pub ahead: Option<u32>,
+    pub behind: Option<u32>,
+    pub upstream: Option<String>,
+}
+
+/// Data for the optional columns of the human view, fetched only when a
+/// column or the sort order needs it.
+#[derive(Debug, Default)]
+struct Details {
+    commit: Option<CommitInfo>,
+    stashes: usize,
+    size: Option<u64>,
+}
+
+struct Row {
+    entry: ListEntry,
+    details: Details,
+}
+
+/// Which optional data `collect_rows` has to fetch.
+#[derive(Default)]
+struct Wanted {
+    commits: bool,
+    stashes: bool,
+    size: bool,
+}
+
+pub fn execute(
+    compact: bool,
+    format: ListFormat,
+    columns: &[ListColumn],
+    long: bool,
+    sort: Option<ListSort>,
+    filters: &ListFilters,
+) -> anyhow::Result<()> {
     let cwd = std::env::current_dir()?;
     let repo = find_bare_repo(&cwd)?;
     let worktrees = list_worktrees(&repo)?;
 
-    // Determine current worktree
-    let cwd_canonical = std::fs::canonicalize(&cwd).ok();
-
-    if compact {
+    if compact && sort.is_none() && filters.is_empty() {
         for wt in &worktrees {
             if wt.is_bare {
                 continue;
@@ -31,87 +111,585 @@ pub fn execute(compact: bool) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    // Collect non-bare worktrees with their computed state
-    let entries: Vec<_> = worktrees
+    let config = read_config(&repo)?;
+    let columns = if format == ListFormat::Human && !compact {
+        resolve_columns(columns, long, &config.list.columns)?
+    } else {
+        Vec::new()
+    };
+    let filter = RowFilter::new(&repo, filters)?;
+    let wanted = Wanted {
+        commits: sort == Some(ListSort::Recent)
+            || filters.stale.is_some()
+            || columns.iter().any(|c| {
+                matches!(
+                    c,
+                    ListColumn::Subject | ListColumn::Date | ListColumn::Author
+                )
+            }),
+        stashes: columns.contains(&ListColumn::Stash),
+        size: columns.contains(&ListColumn::Size),
+    };
+    let mut rows = collect_rows(&repo, worktrees, &cwd, config.status.untracked, &wanted);
+    if !filters.is_empty() {
+        rows.retain(|row| filter.keep(row));
+    }
+    if let Some(sort) = sort {
+        sort_rows(&mut rows, sort);
+    }
+
+    if compact {
+        for branch in rows.iter().filter_map(|r| r.entry.worktree.branch.as_ref()) {
+            println!("{branch}");
+        }
+        return Ok(());
+    }
+
+    let entries = || rows.iter().map(|r| &r.entry);
+    match format {
+        ListFormat::Human => print_human(&rows, &columns),
+        ListFormat::Json => {
+            let entries: Vec<_> = entries().collect();
+            println!("{}", serde_json::to_string_pretty(&entries)?);
+        }
+        ListFormat::Tsv => {
+            for entry in entries() {
+                println!("{}", tsv_line(entry));
+            }
+        }
+        ListFormat::Porcelain => {
+            let mut out = std::io::stdout().lock();
+            for entry in entries() {
+                out.write_all(porcelain_record(entry).as_bytes())?;
+            }
+            out.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Columns for the human view: every column with `--long`, else `--columns`,
+/// else `list.columns`, else just the lock state.
+fn resolve_columns(
+    flag: &[ListColumn],
+    long: bool,
+    configured: &[String],
+) -> anyhow::Result<Vec<ListColumn>> {
+    if long {
+        return Ok(ListColumn::value_variants().to_vec());
+    }
+    if !flag.is_empty() {
+        return Ok(flag.to_vec());
+    }
+    if configured.is_empty() {
+        return Ok(vec![ListColumn::Lock]);
+    }
+    configured
         .iter()
-        .filter(|wt| !wt.is_bare)
-        .map(|wt| {
-            let branch_name = wt
-                .branch
-                .clone()
-                .unwrap_or_else(|| "(detached)".to_string());
+        .map(|name| {
+            ListColumn::from_str(name, true).map_err(|_| {
+                let known: Vec<String> = ListColumn::value_variants()
+                    .iter()
+                    .filter_map(|c| c.to_possible_value())
+                    .map(|v| v.get_name().to_string())
+                    .collect();
+                anyhow!(
+                    "unknown column `{name}` in list.columns (expected one of: {})",
+                    known.join(", ")
+                )
+            })
+        })
+        .collect()
+}
+
+/// `ListFilters` with the glob compiled and merged branches looked up.
+struct RowFilter<'a> {
+    filters: &'a ListFilters,
+    branch: Option<glob::Pattern>,
+    /// Branches merged into the default branch, as `remove --merged` sees them.
+    merged: Vec<String>,
+    now: i64,
+}
+
+impl<'a> RowFilter<'a> {
+    fn new(repo: &Path, filters: &'a ListFilters) -> anyhow::Result<Self> {
+        let branch = filters
+            .branch
+            .as_deref()
+            .map(|glob| {
+                glob::Pattern::new(glob).with_context(|| format!("invalid --branch glob `{glob}`"))
+            })
+            .transpose()?;
+        let merged = if filters.merged {
+            merged_into_default(repo, &default_branch(repo)?)?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            filters,
+            branch,
+            merged,
+            now: log::now(),
+        })
+    }
+
+    fn keep(&self, row: &Row) -> bool {
+        let f = self.filters;
+        let entry = &row.entry;
+        let branch = entry.worktree.branch.as_deref();
+        if entry.worktree.is_bare {
+            return false;
+        }
+        if f.dirty && entry.status != Some(WorktreeStatus::Dirty) {
+            return false;
+        }
+        if f.missing && entry.status != Some(WorktreeStatus::Missing) {
+            return false;
+        }
+        if f.ahead && entry.ahead.unwrap_or(0) == 0 {
+            return false;
+        }
+        if f.behind && entry.behind.unwrap_or(0) == 0 {
+            return false;
+        }
+        if f.merged && !branch.is_some_and(|b| self.merged.iter().any(|m| m == b)) {
+            return false;
+        }
+        if let Some(days) = f.stale {
+            let age = i64::try_from(days)
+                .unwrap_or(i64::MAX)
+                .saturating_mul(86_400);
+            let cutoff = self.now.saturating_sub(age);
+            if row.details.commit.as_ref().is_none_or(|c| c.time >= cutoff) {
+                return false;
+            }
+        }
+        if let Some(pattern) = &self.branch
+            && !branch.is_some_and(|b| pattern.matches(b))
+        {
+            return false;
+        }
+        true
+    }
+}
+
+/// Every worktree with its status, classified the way `grov list` does.
+pub(crate) fn worktree_entries(
+    repo: &Path,
+    cwd: &Path,
+    untracked: UntrackedFiles,
+) -> anyhow::Result<Vec<ListEntry>> {
+    let worktrees = list_worktrees(repo)?;
+    let rows = collect_rows(repo, worktrees, cwd, untracked, &Wanted::default());
+    Ok(rows.into_iter().map(|row| row.entry).collect())
+}
+
+/// Longest a single worktree's `git status` may take before it is shown as unknown.
+pub(crate) const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Compute status, ahead/behind and upstream for every worktree, probing
+/// worktrees concurrently, plus whatever optional data `wanted` asks for.
+fn collect_rows(
+    repo: &Path,
+    worktrees: Vec<WorktreeInfo>,
+    cwd: &Path,
+    untracked: UntrackedFiles,
+    wanted: &Wanted,
+) -> Vec<Row> {
+    // Determine current worktree
+    let cwd_canonical = std::fs::canonicalize(cwd).ok();
+    let probes = probe_all(&worktrees, untracked, wanted.size);
+
+    let mut commits = HashMap::new();
+    if wanted.commits {
+        let shas: Vec<&str> = worktrees
+            .iter()
+            .filter(|wt| !wt.is_bare && wt.head.bytes().any(|b| b != b'0'))
+            .map(|wt| wt.head.as_str())
+            .collect();
+        commits = log::commit_infos(repo, &shas).unwrap_or_default();
+    }
+    let stashes = if wanted.stashes {
+        log::stash_counts(repo).unwrap_or_default()
+    } else {
+        HashMap::new()
+    };
+
+    worktrees
+        .into_iter()
+        .zip(probes)
+        .map(|(wt, (summary, size))| {
             let wt_canonical = std::fs::canonicalize(&wt.path).ok();
-            let is_current = cwd_canonical
-                .as_ref()
-                .zip(wt_canonical.as_ref())
-                .map(|(cwd, root)| cwd == root || cwd.starts_with(root))
-                .unwrap_or(false);
-            let status = if !wt.path.exists() {
-                WorktreeStatus::Missing
-            } else {
-                match status::is_dirty(&wt.path) {
-                    Ok(true) => WorktreeStatus::Dirty,
-                    Ok(false) => WorktreeStatus::Clean,
-                    Err(_) => WorktreeStatus::Unknown,
+            let is_current = !wt.is_bare
+                && cwd_canonical
+                    .as_ref()
+                    .zip(wt_canonical.as_ref())
+                    .map(|(cwd, root)| cwd == root || cwd.starts_with(root))
+                    .unwrap_or(false);
+            let (status, summary) = match summary {
+                Probe::Bare => (None, None),
+                Probe::Missing => (Some(WorktreeStatus::Missing), None),
+                Probe::Failed => (Some(WorktreeStatus::Unknown), None),
+                Probe::Read(summary) if summary.dirty => {
+                    (Some(WorktreeStatus::Dirty), Some(summary))
                 }
+                Probe::Read(summary) => (Some(WorktreeStatus::Clean), Some(summary)),
+            };
+            let ab = summary.as_ref().and_then(|s| s.ahead_behind);
+            let details = Details {
+                commit: commits.get(&wt.head).cloned(),
+                stashes: wt
+                    .branch
+                    .as_ref()
+                    .and_then(|b| stashes.get(b))
+                    .copied()
+                    .unwrap_or(0),
+                size,
+            };
+            let entry = ListEntry {
+                dir_name: worktree_dir_name(&wt),
+                is_detached: !wt.is_bare && wt.branch.is_none(),
+                is_current,
+                status,
+                ahead: ab.map(|(ahead, _)| ahead),
+                behind: ab.map(|(_, behind)| behind),
+                upstream: summary.and_then(|s| s.upstream),
+                worktree: wt,
             };
-            let ab = match status {
-                WorktreeStatus::Clean | WorktreeStatus::Dirty => {
-                    status::ahead_behind(&wt.path).unwrap_or(None)
+            Row { entry, details }
+        })
+        .collect()
+}
+
+enum Probe {
+    Bare,
+    Missing,
+    /// git failed or timed out.
+    Failed,
+    Read(StatusSummary),
+}
+
+/// A worktree's status probe and, when asked for, its size on disk.
+type Probed = (Probe, Option<u64>);
+
+/// Run `status::summary` (and measure disk usage when `with_size` is set)
+/// for every worktree on a bounded pool of threads, returning results in
+/// input order.
+fn probe_all(
+    worktrees: &[WorktreeInfo],
+    untracked: UntrackedFiles,
+    with_size: bool,
+) -> Vec<Probed> {
+    let jobs = std::thread::available_parallelism().map_or(4, |n| n.get().min(8));
+    let next = AtomicUsize::new(0);
+    let probes: Vec<Mutex<Option<Probed>>> = worktrees.iter().map(|_| Mutex::new(None)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(worktrees.len()) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(wt) = worktrees.get(i) else {
+                        break;
+                    };
+                    let probe = if wt.is_bare {
+                        Probe::Bare
+                    } else if !wt.path.exists() {
+                        Probe::Missing
+                    } else {
+                        status::summary(&wt.path, untracked, STATUS_TIMEOUT)
+                            .map_or(Probe::Failed, Probe::Read)
+                    };
+                    let size = (with_size && matches!(probe, Probe::Read(_) | Probe::Failed))
+                        .then(|| dir_size(&wt.path));
+                    *probes[i].lock().expect("probe lock poisoned") = Some((probe, size));
                 }
-                WorktreeStatus::Missing | WorktreeStatus::Unknown => None,
+            });
+        }
+    });
+
+    probes
+        .into_iter()
+        .map(|p| {
+            p.into_inner()
+                .expect("probe lock poisoned")
+                .expect("every worktree is probed")
+        })
+        .collect()
+}
+
+/// Total size in bytes of the files under `path`, not following symlinks.
+fn dir_size(path: &Path) -> u64 {
+    let mut total = 0;
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(meta) = entry.metadata() else {
+                continue;
             };
-            let dir_name = wt
-                .path
-                .file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_default();
-            (branch_name, is_current, status, ab, dir_name)
+            if meta.is_dir() {
+                pending.push(entry.path());
+            } else {
+                total += meta.len();
+            }
+        }
+    }
+    total
+}
+
+/// Sort rows in place, keeping the bare repository entry first.
+fn sort_rows(rows: &mut [Row], sort: ListSort) {
+    match sort {
+        ListSort::Name => rows.sort_by(|a, b| {
+            let key = |r: &Row| {
+                let wt = &r.entry.worktree;
+                (!wt.is_bare, wt.branch.clone().unwrap_or_default())
+            };
+            key(a).cmp(&key(b))
+        }),
+        ListSort::Recent => rows.sort_by_key(|r| {
+            (
+                !r.entry.worktree.is_bare,
+                Reverse(r.details.commit.as_ref().map(|c| c.time)),
+            )
+        }),
+        ListSort::Status => rows.sort_by_key(|r| {
+            let rank = match r.entry.status {
+                None => 0,
+                Some(WorktreeStatus::Dirty) => 1,
+                Some(WorktreeStatus::Missing) => 2,
+                Some(WorktreeStatus::Unknown) => 3,
+                Some(WorktreeStatus::Clean) => 4,
+            };
+            (!r.entry.worktree.is_bare, rank)
+        }),
+    }
+}
+
+fn print_human(rows: &[Row], columns: &[ListColumn]) {
+    let rows: Vec<&Row> = rows.iter().filter(|r| !r.entry.worktree.is_bare).collect();
+    if rows.is_empty() {
+        println!("{}", style("No worktrees found.").dim());
+        return;
+    }
+
+    let now = log::now();
+    let table: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut cells = vec![branch_cell(&row.entry), status_cell(&row.entry)];
+            cells.extend(columns.iter().map(|&c| column_cell(c, row, now)));
+            cells.push(style(format!("({})", row.entry.dir_name)).dim().to_string());
+            cells
         })
         .collect();
 
-    if entries.is_empty() {
-        println!("{}", style("No worktrees found.").dim());
-        return Ok(());
+    // Optional columns that are blank on every row are left out entirely
+    let last = table[0].len() - 1;
+    let shown: Vec<usize> = (0..=last)
+        .filter(|&i| i < 2 || i == last || table.iter().any(|cells| !cells[i].is_empty()))
+        .collect();
+    // Width on screen, so wide and combining characters line up
+    let widths: Vec<usize> = (0..=last)
+        .map(|i| {
+            table
+                .iter()
+                .map(|cells| measure_text_width(&cells[i]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for cells in &table {
+        let line: Vec<String> = shown
+            .iter()
+            .map(|&i| {
+                if i == last {
+                    cells[i].clone()
+                } else {
+                    pad_str(&cells[i], widths[i], Alignment::Left, None).into_owned()
+                }
+            })
+            .collect();
+        println!("  {}", line.join("  "));
     }
+}
 
-    // Find max branch name length for alignment
-    let max_branch = entries.iter().map(|(b, ..)| b.len()).max().unwrap_or(0);
+/// Marker and branch name.
+fn branch_cell(entry: &ListEntry) -> String {
+    let branch = entry.worktree.branch.as_deref().unwrap_or("(detached)");
+    if entry.is_current {
+        format!(
+            "{} {}",
+            style("●").cyan().bold(),
+            style(branch).cyan().bold()
+        )
+    } else {
+        format!("{} {branch}", style("○").dim())
+    }
+}
 
-    for (branch_name, is_current, status, ab, dir_name) in &entries {
-        // Marker + branch
-        let (marker, branch_display) = if *is_current {
-            (
-                style("●").cyan().bold().to_string(),
-                style(branch_name).cyan().bold().to_string(),
-            )
-        } else {
-            (style("○").dim().to_string(), style(branch_name).to_string())
-        };
+/// Status indicator followed by ahead/behind counts.
+fn status_cell(entry: &ListEntry) -> String {
+    let status = entry.status.unwrap_or(WorktreeStatus::Unknown);
+    let label = style(status.label());
+    let status = match status {
+        WorktreeStatus::Clean => label.green(),
+        WorktreeStatus::Dirty | WorktreeStatus::Unknown => label.yellow(),
+        WorktreeStatus::Missing => label.red(),
+    };
+    format!(
+        "{status}{}",
+        format_ahead_behind(entry.ahead.zip(entry.behind))
+    )
+}
 
-        // Status indicator
-        let status_str = match status {
-            WorktreeStatus::Clean => style("✓ clean").green().to_string(),
-            WorktreeStatus::Dirty => style("✦ dirty").yellow().to_string(),
-            WorktreeStatus::Missing => style("! missing").red().to_string(),
-            WorktreeStatus::Unknown => style("? unknown").yellow().to_string(),
-        };
+/// Longest commit subject shown before it is cut with an ellipsis.
+const SUBJECT_WIDTH: usize = 50;
 
-        // Ahead/behind
-        let ab_str = format_ahead_behind(*ab);
+fn column_cell(column: ListColumn, row: &Row, now: i64) -> String {
+    let entry = &row.entry;
+    let commit = row.details.commit.as_ref();
+    match column {
+        ListColumn::Subject => commit
+            .map(|c| truncate_str(&c.subject, SUBJECT_WIDTH, "…").into_owned())
+            .unwrap_or_default(),
+        ListColumn::Date => commit
+            .map(|c| style(log::relative_age(c.time, now)).dim().to_string())
+            .unwrap_or_default(),
+        ListColumn::Author => commit.map(|c| c.author.clone()).unwrap_or_default(),
+        ListColumn::Upstream => entry
+            .upstream
+            .as_ref()
+            .map(|u| style(u).dim().to_string())
+            .unwrap_or_default(),
+        ListColumn::Gone => {
+            // status reports an upstream without ahead/behind once it is deleted
+            if entry.upstream.is_some() && entry.ahead.is_none() {
+                style("[gone]").red().to_string()
+            } else {
+                String::new()
+            }
+        }
+        ListColumn::Stash => match row.details.stashes {
+            0 => String::new(),
+            1 => style("1 stash").yellow().to_string(),
+            n => style(format!("{n} stashes")).yellow().to_string(),
+        },
+        ListColumn::Lock => match (&entry.worktree.locked, &entry.worktree.prunable) {
+            (Some(reason), _) if reason.is_empty() => style("locked").magenta().to_string(),
+            (Some(reason), _) => format!(
+                "{} {}",
+                style("locked").magenta(),
+                style(format!("({reason})")).dim()
+            ),
+            (None, Some(_)) => style("prunable").red().to_string(),
+            (None, None) => String::new(),
+        },
+        ListColumn::Size => row.details.size.map(format_size).unwrap_or_default(),
+    }
+}
 
-        // Directory name in dim
-        let path_str = style(format!("({dir_name})")).dim().to_string();
+/// Human-readable size with binary units, like `du -h`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["K", "M", "G", "T"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1}{}", UNITS[unit])
+}
 
-        let padded_branch = format!(
-            "{branch_display}{}",
-            " ".repeat(max_branch.saturating_sub(branch_name.len()))
-        );
+/// `path  dir_name  branch  head  bare  detached  current  status  ahead  behind  upstream  locked  prunable`
+///
+/// Backslashes, tabs and newlines in text fields are escaped as `\\`, `\t`
+/// and `\n`, so every worktree stays on one line.
+fn tsv_line(entry: &ListEntry) -> String {
+    let opt_num = |n: Option<u32>| n.map(|n| n.to_string()).unwrap_or_default();
+    [
+        tsv_escape(&entry.worktree.path.display().to_string()),
+        tsv_escape(&entry.dir_name),
+        tsv_escape(entry.worktree.branch.as_deref().unwrap_or_default()),
+        entry.worktree.head.clone(),
+        entry.worktree.is_bare.to_string(),
+        entry.is_detached.to_string(),
+        entry.is_current.to_string(),
+        entry
+            .status
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        opt_num(entry.ahead),
+        opt_num(entry.behind),
+        tsv_escape(entry.upstream.as_deref().unwrap_or_default()),
+        entry.worktree.locked.is_some().to_string(),
+        entry.worktree.prunable.is_some().to_string(),
+    ]
+    .join("\t")
+}
 
-        println!("  {marker} {padded_branch}  {status_str}{ab_str}  {path_str}",);
+fn tsv_escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+/// One record of `--format porcelain`: NUL-terminated `key value` attributes
+/// (bare flags without a value), followed by an empty attribute.
+fn porcelain_record(entry: &ListEntry) -> String {
+    let wt = &entry.worktree;
+    let mut attrs = vec![
+        format!("worktree {}", wt.path.display()),
+        format!("dir {}", entry.dir_name),
+        format!("HEAD {}", wt.head),
+    ];
+    if let Some(ref branch) = wt.branch {
+        attrs.push(format!("branch {branch}"));
+    }
+    if wt.is_bare {
+        attrs.push("bare".to_string());
+    }
+    if entry.is_detached {
+        attrs.push("detached".to_string());
+    }
+    if entry.is_current {
+        attrs.push("current".to_string());
+    }
+    if let Some(status) = entry.status {
+        attrs.push(format!("status {}", status.as_str()));
+    }
+    if let (Some(ahead), Some(behind)) = (entry.ahead, entry.behind) {
+        attrs.push(format!("ahead {ahead}"));
+        attrs.push(format!("behind {behind}"));
+    }
+    if let Some(ref upstream) = entry.upstream {
+        attrs.push(format!("upstream {upstream}"));
+    }
+    // Same shape as `git worktree list --porcelain`: a flag, or a flag and reason
+    for (name, value) in [("locked", &wt.locked), ("prunable", &wt.prunable)] {
+        match value.as_deref() {
+            Some("") => attrs.push(name.to_string()),
+            Some(reason) => attrs.push(format!("{name} {reason}")),
+            None => {}
+        }
     }
 
-    Ok(())
+    let mut record = String::new();
+    for attr in attrs {
+        record.push_str(&attr);
+        record.push('\0');
+    }
+    record.push('\0');
+    record
 }
 
 fn format_ahead_behind(ab: Option<(u32, u32)>) -> String {
@@ -133,3 +711,209 @@ fn format_ahead_behind(ab: Option<(u32, u32)>) -> String {
         None => String::new(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn entry() -> ListEntry {
+        ListEntry {
+            worktree: WorktreeInfo {
+                path: PathBuf::from("/project/dev_feature"),
+                head: "abc1234".to_string(),
+                branch: Some("feature/x".to_string()),
+                is_bare: false,
+                locked: None,
+                prunable: None,
+            },
+            dir_name: "dev_feature".to_string(),
+            is_detached: false,
+            is_current: true,
+            status: Some(WorktreeStatus::Dirty),
+            ahead: Some(2),
+            behind: Some(0),
+            upstream: Some("origin/feature/x".to_string()),
+        }
+    }
+
+    #[test]
+    fn tsv_columns() {
+        assert_eq!(
+            tsv_line(&entry()),
+            "/project/dev_feature\tdev_feature\tfeature/x\tabc1234\tfalse\tfalse\ttrue\tdirty\t2\t0\torigin/feature/x\tfalse\tfalse"
+        );
+    }
+
+    #[test]
+    fn tsv_escapes_text_fields() {
+        let mut entry = entry();
+        entry.worktree.path = PathBuf::from("/project/a\tb\nc\\d");
+        let line = tsv_line(&entry);
+        assert_eq!(line.split('\t').count(), 13);
+        assert!(line.starts_with("/project/a\\tb\\nc\\\\d\t"), "{line}");
+    }
+
+    #[test]
+    fn porcelain_record_attributes() {
+        let record = porcelain_record(&entry());
+        let attrs: Vec<&str> = record.split('\0').collect();
+        assert_eq!(
+            attrs,
+            vec![
+                "worktree /project/dev_feature",
+                "dir dev_feature",
+                "HEAD abc1234",
+                "branch feature/x",
+                "current",
+                "status dirty",
+                "ahead 2",
+                "behind 0",
+                "upstream origin/feature/x",
+                "",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn porcelain_record_lock_state() {
+        let mut entry = entry();
+        entry.worktree.locked = Some(String::new());
+        entry.worktree.prunable = Some("gitdir file points to non-existent location".into());
+        let record = porcelain_record(&entry);
+        let attrs: Vec<&str> = record.split('\0').collect();
+        assert!(attrs.contains(&"locked"));
+        assert!(attrs.contains(&"prunable gitdir file points to non-existent location"));
+    }
+
+    fn row(branch: &str, status: WorktreeStatus, time: i64) -> Row {
+        let mut entry = entry();
+        entry.worktree.branch = Some(branch.to_string());
+        entry.status = Some(status);
+        Row {
+            entry,
+            details: Details {
+                commit: Some(CommitInfo {
+                    subject: String::new(),
+                    author: String::new(),
+                    time,
+                }),
+                ..Details::default()
+            },
+        }
+    }
+
+    fn branches(rows: &[Row]) -> Vec<&str> {
+        rows.iter()
+            .map(|r| r.entry.worktree.branch.as_deref().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn sorts_rows() {
+        let mut rows = vec![
+            row("b", WorktreeStatus::Clean, 300),
+            row("c", WorktreeStatus::Dirty, 100),
+            row("a", WorktreeStatus::Missing, 200),
+        ];
+        sort_rows(&mut rows, ListSort::Name);
+        assert_eq!(branches(&rows), ["a", "b", "c"]);
+        sort_rows(&mut rows, ListSort::Recent);
+        assert_eq!(branches(&rows), ["b", "a", "c"]);
+        sort_rows(&mut rows, ListSort::Status);
+        assert_eq!(branches(&rows), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn columns_fall_back_to_config_then_lock() {
+        assert_eq!(
+            resolve_columns(&[], false, &[]).unwrap(),
+            [ListColumn::Lock]
+        );
+        assert_eq!(
+            resolve_columns(&[], false, &["Date".into(), "size".into()]).unwrap(),
+            [ListColumn::Date, ListColumn::Size]
+        );
+        assert_eq!(
+            resolve_columns(&[ListColumn::Author], false, &["date".into()]).unwrap(),
+            [ListColumn::Author]
+        );
+        assert_eq!(
+            resolve_columns(&[], true, &[]).unwrap().len(),
+            ListColumn::value_variants().len()
+        );
+        assert!(resolve_columns(&[], false, &["bogus".into()]).is_err());
+    }
+
+    #[test]
+    fn gone_upstream_column() {
+        let mut row = row("a", WorktreeStatus::Clean, 0);
+        assert_eq!(column_cell(ListColumn::Gone, &row, 0), "");
+        row.entry.ahead = None;
+        row.entry.behind = None;
+        assert!(column_cell(ListColumn::Gone, &row, 0).contains("[gone]"));
+    }
+
+    #[test]
+    fn filters_on_ahead_and_behind() {
+        fn filter(filters: &ListFilters) -> RowFilter<'_> {
+            RowFilter {
+                filters,
+                branch: None,
+                merged: vec![],
+                now: 0,
+            }
+        }
+        let ahead = row("a", WorktreeStatus::Clean, 0);
+        let mut untracked = row("b", WorktreeStatus::Clean, 0);
+        untracked.entry.ahead = None;
+        untracked.entry.behind = None;
+
+        let filters = ListFilters {
+            ahead: true,
+            ..ListFilters::default()
+        };
+        assert!(filter(&filters).keep(&ahead));
+        assert!(!filter(&filters).keep(&untracked));
+        let filters = ListFilters {
+            behind: true,
+            ..ListFilters::default()
+        };
+        assert!(!filter(&filters).keep(&ahead));
+    }
+
+    #[test]
+    fn stale_filter_saturates_huge_day_counts() {
+        let filters = ListFilters {
+            stale: Some(u64::MAX),
+            ..ListFilters::default()
+        };
+        let filter = RowFilter {
+            filters: &filters,
+            branch: None,
+            merged: vec![],
+            now: 1_700_000_000,
+        };
+        assert!(!filter.keep(&row("a", WorktreeStatus::Clean, 0)));
+        assert!(filter.keep(&row("b", WorktreeStatus::Clean, i64::MIN)));
+    }
+
+    #[test]
+    fn formats_sizes() {
+        assert_eq!(format_size(512), "512B");
+        assert_eq!(format_size(1536), "1.5K");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0G");
+    }
+
+    #[test]
+    fn json_schema_is_flat() {
+        let value = serde_json::to_value(entry()).unwrap();
+        assert_eq!(value["path"], "/project/dev_feature");
+        assert_eq!(value["branch"], "feature/x");
+        assert_eq!(value["is_bare"], false);
+        assert_eq!(value["status"], "dirty");
+        assert_eq!(value["ahead"], 2);
+    }
+}
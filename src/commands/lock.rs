@@ -0,0 +1,70 @@
+use console::style;
+
+use crate::cli::MatchMode;
+use crate::commands::remove::resolve_by_name;
+use crate::git::repo::find_bare_repo;
+use crate::git::worktree::{
+    WorktreeInfo, list_worktrees, lock_worktree, unlock_worktree, worktree_dir_name,
+};
+
+pub fn execute_lock(name: &str, match_mode: MatchMode, reason: Option<&str>) -> anyhow::Result<()> {
+    let (repo, wt) = resolve(name, match_mode)?;
+    if let Some(existing) = &wt.locked {
+        let existing = if existing.is_empty() {
+            String::new()
+        } else {
+            format!(" ({existing})")
+        };
+        anyhow::bail!(
+            "worktree at {} is already locked{existing}",
+            wt.path.display()
+        );
+    }
+
+    lock_worktree(&repo, &wt.path, reason)?;
+
+    let reason = reason
+        .map(|r| style(format!(" ({r})")).dim().to_string())
+        .unwrap_or_default();
+    println!(
+        "{} Locked {}{reason}",
+        style("\u{2713}").green().bold(),
+        style(display_name(&wt)).cyan().bold(),
+    );
+    Ok(())
+}
+
+pub fn execute_unlock(name: &str, match_mode: MatchMode) -> anyhow::Result<()> {
+    let (repo, wt) = resolve(name, match_mode)?;
+    if wt.locked.is_none() {
+        anyhow::bail!("worktree at {} is not locked", wt.path.display());
+    }
+
+    unlock_worktree(&repo, &wt.path)?;
+
+    println!(
+        "{} Unlocked {}",
+        style("\u{2713}").green().bold(),
+        style(display_name(&wt)).cyan().bold(),
+    );
+    Ok(())
+}
+
+fn resolve(
+    name: &str,
+    match_mode: MatchMode,
+) -> anyhow::Result<(std::path::PathBuf, WorktreeInfo)> {
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let mut worktrees = list_worktrees(&repo)?;
+
+    let wt = worktrees.swap_remove(resolve_by_name(&worktrees, name, match_mode)?);
+    if wt.is_bare {
+        anyhow::bail!("cannot lock or unlock the bare repository entry");
+    }
+    Ok((repo, wt))
+}
+
+fn display_name(wt: &WorktreeInfo) -> String {
+    wt.branch.clone().unwrap_or_else(|| worktree_dir_name(wt))
+}
@@ -1,5 +1,14 @@
 pub mod add;
 pub mod completions;
+pub mod config;
+pub mod foreach;
 pub mod init;
 pub mod list;
+pub mod lock;
+pub mod mv;
+pub mod prune;
 pub mod remove;
+pub mod shell_init;
+pub mod status;
+pub mod switch;
+pub mod ui;
@@ -0,0 +1,151 @@
+use std::path::Path;
+
+use anyhow::Context;
+use console::style;
+
+use crate::branch::BranchPolicy;
+use crate::cli::MatchMode;
+use crate::commands::remove::resolve_by_name;
+use crate::commands::shell_init::request_cd;
+use crate::config::read_config;
+use crate::git::repo::find_bare_repo;
+use crate::git::status;
+use crate::git::worktree::{
+    branch_exists_local, branch_exists_remote, list_worktrees, move_worktree, rename_branch,
+    set_upstream, worktree_dir_name,
+};
+use crate::paths::{relative_from, worktree_path};
+
+pub fn execute(
+    name: &str,
+    new_branch: &str,
+    match_mode: MatchMode,
+    custom_path: Option<&Path>,
+    update_upstream: bool,
+) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let config = read_config(&repo)?;
+    let worktrees = list_worktrees(&repo)?;
+
+    let wt = &worktrees[resolve_by_name(&worktrees, name, match_mode)?];
+    if wt.is_bare {
+        anyhow::bail!("cannot move the bare repository entry");
+    }
+    let Some(old_branch) = wt.branch.as_deref() else {
+        anyhow::bail!(
+            "worktree at {} has a detached HEAD; there is no branch to rename",
+            wt.path.display()
+        );
+    };
+    if let Some(reason) = &wt.locked {
+        let reason = if reason.is_empty() {
+            String::new()
+        } else {
+            format!(" ({reason})")
+        };
+        anyhow::bail!(
+            "worktree at {} is locked{reason}; run `grov unlock {}` first",
+            wt.path.display(),
+            worktree_dir_name(wt)
+        );
+    }
+    if !wt.path.exists() {
+        anyhow::bail!("worktree directory is missing: {}", wt.path.display());
+    }
+
+    let renames = new_branch != old_branch;
+    if renames {
+        BranchPolicy::from_config(&config.branch)?.check(new_branch)?;
+        if branch_exists_local(&repo, new_branch) {
+            anyhow::bail!("branch {new_branch} already exists");
+        }
+    }
+
+    let target = match custom_path {
+        Some(path) => cwd.join(path),
+        None => worktree_path(&repo, new_branch, &config.worktree)?,
+    };
+    let moves = target != wt.path;
+    if moves && (target.symlink_metadata().is_ok() || worktrees.iter().any(|w| w.path == target)) {
+        anyhow::bail!("target already exists: {}", target.display());
+    }
+    if !renames && !moves {
+        anyhow::bail!("{old_branch} is already at {}", wt.path.display());
+    }
+
+    let old_upstream = status::upstream(&wt.path).unwrap_or(None);
+    let cwd_inside = std::fs::canonicalize(&cwd)
+        .ok()
+        .zip(std::fs::canonicalize(&wt.path).ok())
+        .is_some_and(|(cwd, root)| cwd.starts_with(root));
+
+    // Move first: it is the step most likely to fail, and a branch rename
+    // is easy to put back afterwards
+    if moves {
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        move_worktree(&repo, &wt.path, &target)?;
+    }
+    if renames && let Err(err) = rename_branch(&repo, old_branch, new_branch) {
+        if moves {
+            move_worktree(&repo, &target, &wt.path).context("could not move the worktree back")?;
+        }
+        return Err(err).context(format!("could not rename {old_branch}"));
+    }
+
+    if renames {
+        println!(
+            "{} Renamed branch {} to {}",
+            style("\u{2713}").green().bold(),
+            style(old_branch).cyan(),
+            style(new_branch).cyan().bold(),
+        );
+    }
+    if moves {
+        println!(
+            "{} Moved worktree to {}",
+            style("\u{2713}").green().bold(),
+            style(target.display()).bold(),
+        );
+    }
+
+    if let Some(upstream) = old_upstream.filter(|_| renames) {
+        let new_upstream = format!("origin/{new_branch}");
+        if !update_upstream {
+            eprintln!(
+                "{} still tracking {upstream}; pass --set-upstream to track {new_upstream}",
+                style("note:").cyan().bold()
+            );
+        } else if branch_exists_remote(&repo, new_branch) {
+            set_upstream(&repo, new_branch, Some(&new_upstream))?;
+            println!(
+                "{} Tracking {}",
+                style("\u{2713}").green().bold(),
+                style(&new_upstream).bold()
+            );
+        } else {
+            set_upstream(&repo, new_branch, None)?;
+            eprintln!(
+                "{} {new_upstream} does not exist yet; stopped tracking {upstream}. \
+                 Push with: git push -u origin {new_branch}",
+                style("note:").cyan().bold()
+            );
+        }
+    }
+
+    // The shell is still in the old directory; follow the worktree
+    if cwd_inside && moves && !request_cd(&target)? {
+        println!(
+            "{}",
+            style(format!(
+                "  Your shell is in the old location:  cd {}",
+                relative_from(&target, &cwd).display()
+            ))
+            .dim()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,509 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use console::style;
+use crossterm::event;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::commands::list::WorktreeStatus;
+use crate::config::read_config;
+use crate::git::executor::run_git_ok;
+use crate::git::repo::find_bare_repo;
+use crate::git::status::is_dirty;
+use crate::git::worktree::{WorktreeInfo, list_worktrees};
+use crate::tui::select_list::{self, SelectList, SelectResult};
+use crate::tui::terminal::run_tui;
+use crate::tui::theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PruneKind {
+    /// Registered worktree whose directory no longer exists.
+    Missing,
+    /// Worktree directory linked to this repo that git does not know about.
+    Orphaned,
+    /// Worktree directory whose `.git` file cannot be parsed.
+    BrokenLink,
+    /// `worktrees/<id>` admin dir without a usable `gitdir` file.
+    StaleAdmin,
+}
+
+impl PruneKind {
+    fn label(self) -> &'static str {
+        match self {
+            PruneKind::Missing => "missing",
+            PruneKind::Orphaned => "orphaned",
+            PruneKind::BrokenLink => "broken",
+            PruneKind::StaleAdmin => "stale",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            PruneKind::Missing => "registered, but the directory is gone",
+            PruneKind::Orphaned => "directory is not a registered worktree",
+            PruneKind::BrokenLink => "directory has an unreadable .git file",
+            PruneKind::StaleAdmin => "admin dir has no worktree",
+        }
+    }
+
+    /// Whether pruning deletes a directory rather than git metadata.
+    fn deletes_dir(self) -> bool {
+        matches!(self, PruneKind::Orphaned | PruneKind::BrokenLink)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PruneCandidate {
+    pub kind: PruneKind,
+    /// The path shown to the user.
+    pub path: PathBuf,
+    /// The directory that is gone once the candidate is pruned: the worktree
+    /// directory, or the admin dir `git worktree prune` removes.
+    pub remove: PathBuf,
+    /// Working tree status of an orphaned directory, `Unknown` if git cannot
+    /// read it. `None` for other kinds.
+    pub status: Option<WorktreeStatus>,
+}
+
+impl PruneCandidate {
+    /// Why the candidate is left alone, or `None` if it may be pruned.
+    ///
+    /// Directories are only deleted with `delete_dirs`. Unattended runs
+    /// (`--yes`) additionally keep broken links and orphans that are not
+    /// known to be clean.
+    fn skip_reason(&self, delete_dirs: bool, unattended: bool) -> Option<&'static str> {
+        if !self.kind.deletes_dir() {
+            return None;
+        }
+        if !delete_dirs {
+            return Some("directory kept (use --delete-dirs)");
+        }
+        if !unattended {
+            return None;
+        }
+        match (self.kind, self.status) {
+            (PruneKind::Orphaned, Some(WorktreeStatus::Clean)) => None,
+            (PruneKind::Orphaned, Some(WorktreeStatus::Dirty)) => Some("uncommitted changes"),
+            (PruneKind::Orphaned, _) => Some("status unknown"),
+            _ => Some("never deleted with --yes"),
+        }
+    }
+}
+
+pub fn execute(dry_run: bool, yes: bool, delete_dirs: bool) -> anyhow::Result<()> {
+    use std::io::IsTerminal;
+
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let worktrees = list_worktrees(&repo)?;
+    let config = read_config(&repo)?;
+    let candidates = find_candidates(&repo, &worktrees, &config.worktree.path_template)?;
+
+    if candidates.is_empty() {
+        println!("{}", style("Nothing to prune.").dim());
+        return Ok(());
+    }
+
+    print_report(&candidates);
+
+    if dry_run {
+        return Ok(());
+    }
+
+    let (allowed, skipped): (Vec<usize>, Vec<usize>) =
+        (0..candidates.len()).partition(|&i| candidates[i].skip_reason(delete_dirs, yes).is_none());
+    for &i in &skipped {
+        let candidate = &candidates[i];
+        let reason = candidate.skip_reason(delete_dirs, yes).unwrap_or_default();
+        println!(
+            "{} Skipped {} {} ({reason})",
+            style("!").yellow().bold(),
+            candidate.kind.label(),
+            candidate.path.display(),
+        );
+    }
+
+    // Git prunes stale registrations all at once, so they are picked as one entry
+    let (metadata, dirs): (Vec<usize>, Vec<usize>) = allowed
+        .into_iter()
+        .partition(|&i| !candidates[i].kind.deletes_dir());
+    let (prune_metadata, selected): (bool, Vec<usize>) = if yes
+        || (metadata.is_empty() && dirs.is_empty())
+    {
+        (!metadata.is_empty(), dirs)
+    } else {
+        if !std::io::stdin().is_terminal() {
+            anyhow::bail!("--yes is required when stdin is not a terminal");
+        }
+        let offered: Vec<PruneCandidate> = dirs.iter().map(|&i| candidates[i].clone()).collect();
+        let (prune_metadata, picked) = run_prune_picker(metadata.len(), &offered)?;
+        (
+            prune_metadata,
+            picked.into_iter().map(|i| dirs[i]).collect(),
+        )
+    };
+
+    if prune_metadata {
+        prune_registrations(&repo, metadata.iter().map(|&i| &candidates[i]));
+    }
+
+    for &i in &selected {
+        let candidate = &candidates[i];
+        match std::fs::remove_dir_all(&candidate.remove) {
+            Ok(()) => println!(
+                "{} Pruned {} {}",
+                style("\u{2713}").green().bold(),
+                candidate.kind.label(),
+                style(candidate.path.display()).bold(),
+            ),
+            Err(e) => eprintln!(
+                "{} Could not prune {}: {e}",
+                style("!").yellow().bold(),
+                candidate.path.display(),
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Drop `missing` and `stale` registrations with `git worktree prune`, which
+/// applies git's own lock and gitdir checks, and report what it removed.
+fn prune_registrations<'a>(repo: &Path, candidates: impl Iterator<Item = &'a PruneCandidate>) {
+    if let Err(e) = run_git_ok(Some(repo), &["worktree", "prune", "--expire", "now"]) {
+        eprintln!(
+            "{} Could not prune worktree registrations: {e}",
+            style("!").yellow().bold(),
+        );
+        return;
+    }
+    for candidate in candidates {
+        if candidate.remove.exists() {
+            eprintln!(
+                "{} Could not prune {}: git kept its registration",
+                style("!").yellow().bold(),
+                candidate.path.display(),
+            );
+        } else {
+            println!(
+                "{} Pruned {} {}",
+                style("\u{2713}").green().bold(),
+                candidate.kind.label(),
+                style(candidate.path.display()).bold(),
+            );
+        }
+    }
+}
+
+fn print_report(candidates: &[PruneCandidate]) {
+    println!("Found {} prunable entries:\n", candidates.len());
+    for candidate in candidates {
+        let kind = format!("{:<8}", candidate.kind.label());
+        let kind = match candidate.kind {
+            PruneKind::Missing | PruneKind::StaleAdmin => style(kind).yellow(),
+            PruneKind::Orphaned | PruneKind::BrokenLink => style(kind).red(),
+        };
+        let description = match candidate.status {
+            Some(status) => format!("({}, {})", candidate.kind.description(), status.as_str()),
+            None => format!("({})", candidate.kind.description()),
+        };
+        println!(
+            "  {kind}  {}  {}",
+            candidate.path.display(),
+            style(description).dim(),
+        );
+    }
+    println!();
+}
+
+/// Collect everything that `grov prune` can clean up.
+pub(crate) fn find_candidates(
+    repo: &Path,
+    worktrees: &[WorktreeInfo],
+    template: &str,
+) -> anyhow::Result<Vec<PruneCandidate>> {
+    let mut candidates = Vec::new();
+    let admin_root = repo.join("worktrees");
+
+    // Admin dirs, keyed by the worktree path their `gitdir` file points at
+    let mut admin_dirs = Vec::new();
+    if admin_root.is_dir() {
+        for entry in std::fs::read_dir(&admin_root)? {
+            let admin = entry?.path();
+            if !admin.is_dir() {
+                continue;
+            }
+            let worktree_path = std::fs::read_to_string(admin.join("gitdir"))
+                .ok()
+                .and_then(|contents| parse_admin_gitdir(&contents));
+            match worktree_path {
+                Some(path) => admin_dirs.push((admin, path)),
+                None => candidates.push(PruneCandidate {
+                    kind: PruneKind::StaleAdmin,
+                    path: admin.clone(),
+                    remove: admin,
+                    status: None,
+                }),
+            }
+        }
+    }
+
+    for wt in worktrees.iter().filter(|wt| !wt.is_bare) {
+        if wt.path.exists() {
+            continue;
+        }
+        let admin = admin_dirs
+            .iter()
+            .find(|(admin, path)| *path == wt.path && !admin.join("locked").exists());
+        if let Some((admin, _)) = admin {
+            candidates.push(PruneCandidate {
+                kind: PruneKind::Missing,
+                path: wt.path.clone(),
+                remove: admin.clone(),
+                status: None,
+            });
+        }
+    }
+
+    // Directories where worktrees live that look like worktrees of this repo
+    let registered: HashSet<PathBuf> = worktrees
+        .iter()
+        .filter_map(|wt| std::fs::canonicalize(&wt.path).ok())
+        .collect();
+    let admin_roots = [
+        std::fs::canonicalize(&admin_root).unwrap_or_else(|_| admin_root.clone()),
+        admin_root,
+    ];
+    let repo_canonical = std::fs::canonicalize(repo)?;
+    let parent = repo_canonical
+        .parent()
+        .expect("bare repo must have a parent dir");
+
+    for dir in worktree_dirs(parent, &repo_canonical, template) {
+        let dot_git = dir.join(".git");
+        if !dot_git.is_file() {
+            continue;
+        }
+        let canonical = std::fs::canonicalize(&dir).unwrap_or_else(|_| dir.clone());
+        if registered.contains(&canonical) {
+            continue;
+        }
+        let (kind, status) = match std::fs::read_to_string(&dot_git)
+            .ok()
+            .and_then(|contents| parse_dot_git_file(&contents))
+        {
+            Some(gitdir) => {
+                let gitdir = dir.join(gitdir);
+                let gitdir = std::fs::canonicalize(&gitdir).unwrap_or(gitdir);
+                // Linked to another repository, not ours to touch
+                if !admin_roots.iter().any(|root| gitdir.starts_with(root)) {
+                    continue;
+                }
+                // Git can still read it if the admin dir survived, e.g. a moved worktree
+                let status = match is_dirty(&dir) {
+                    Ok(true) => WorktreeStatus::Dirty,
+                    Ok(false) => WorktreeStatus::Clean,
+                    Err(_) => WorktreeStatus::Unknown,
+                };
+                (PruneKind::Orphaned, Some(status))
+            }
+            None => (PruneKind::BrokenLink, None),
+        };
+        candidates.push(PruneCandidate {
+            kind,
+            path: dir.clone(),
+            remove: dir,
+            status,
+        });
+    }
+
+    Ok(candidates)
+}
+
+/// Directories that may hold worktrees: the siblings of the bare repo, plus
+/// the ones `worktree.path_template` nests worktrees in, e.g. `trees/*` for
+/// `trees/{branch}` or `*/*` for `{ticket}/{branch_leaf}`.
+fn worktree_dirs(project: &Path, repo: &Path, template: &str) -> Vec<PathBuf> {
+    let mut dirs = subdirs(project, repo);
+
+    let components: Vec<&str> = template
+        .split('/')
+        .filter(|c| !c.is_empty() && *c != ".")
+        .collect();
+    let fixed = components.iter().take_while(|c| !c.contains('{')).count();
+    if components.len() > 1 && fixed < components.len() && !components.contains(&"..") {
+        let mut level = vec![project.join(components[..fixed].join("/"))];
+        for _ in fixed..components.len() {
+            // Worktrees themselves are not searched for nested ones
+            level = level
+                .iter()
+                .filter(|dir| !dir.join(".git").exists())
+                .flat_map(|dir| subdirs(dir, repo))
+                .collect();
+        }
+        dirs.extend(level);
+    }
+
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+fn subdirs(dir: &Path, repo: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir() && path != repo)
+        .collect()
+}
+
+/// Parse a worktree's `.git` file (`gitdir: <path>`).
+fn parse_dot_git_file(contents: &str) -> Option<PathBuf> {
+    let path = contents.trim().strip_prefix("gitdir:")?.trim();
+    if path.is_empty() {
+        return None;
+    }
+    Some(PathBuf::from(path))
+}
+
+/// Parse an admin dir's `gitdir` file (`<worktree>/.git`) into the worktree path.
+fn parse_admin_gitdir(contents: &str) -> Option<PathBuf> {
+    let path = Path::new(contents.trim());
+    if path.file_name()? != ".git" {
+        return None;
+    }
+    path.parent().map(Path::to_path_buf)
+}
+
+/// Pick what to prune: the stale registrations as a single entry (if there
+/// are any), then each directory. Returns whether the registrations were
+/// picked and the indices of the picked directories.
+fn run_prune_picker(
+    registrations: usize,
+    dirs: &[PruneCandidate],
+) -> anyhow::Result<(bool, Vec<usize>)> {
+    let offset = usize::from(registrations > 0);
+    let mut items = Vec::new();
+    let mut hints = Vec::new();
+    if registrations > 0 {
+        items.push(format!(
+            "{:<8}  {registrations} stale registrations (git worktree prune)",
+            "metadata"
+        ));
+        hints.push(vec![]);
+    }
+    for c in dirs {
+        items.push(format!("{:<8}  {}", c.kind.label(), c.path.display()));
+        // Deleting a directory with changes is allowed, but called out
+        hints.push(match c.status {
+            Some(status @ (WorktreeStatus::Dirty | WorktreeStatus::Unknown)) => {
+                vec![Span::styled(status.label(), theme::WARNING)]
+            }
+            _ => vec![],
+        });
+    }
+    // Metadata-only cleanups are safe; deleting directories is opt-in
+    let preselected = 0..offset;
+
+    run_tui(|terminal| {
+        let mut select = SelectList::new("Select entries to prune", items, vec![])
+            .with_multi_select(preselected)
+            .with_hints(hints);
+
+        loop {
+            terminal.draw(|frame| {
+                let area = frame.area();
+                let widget_area = Rect::new(0, 1, area.width, area.height.saturating_sub(3));
+                select.render(frame, widget_area);
+
+                let help_y = area.height.saturating_sub(1);
+                let help_line = Line::from(Span::styled(
+                    format!("  {}", theme::HELP_MULTI_SELECT),
+                    theme::DIM,
+                ));
+                frame.render_widget(
+                    Paragraph::new(help_line),
+                    Rect::new(0, help_y, area.width, 1),
+                );
+            })?;
+
+            if event::poll(std::time::Duration::from_millis(100))? {
+                match select.handle_event(&event::read()?) {
+                    select_list::Action::Selected(SelectResult::Items(indices)) => {
+                        let picked = indices
+                            .iter()
+                            .filter(|&&i| i >= offset)
+                            .map(|&i| i - offset)
+                            .collect();
+                        return Ok((offset > 0 && indices.contains(&0), picked));
+                    }
+                    select_list::Action::Selected(_) => unreachable!(),
+                    select_list::Action::Cancel => anyhow::bail!("cancelled"),
+                    select_list::Action::Continue => {}
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(kind: PruneKind, status: Option<WorktreeStatus>) -> PruneCandidate {
+        PruneCandidate {
+            kind,
+            path: PathBuf::from("/p/wt"),
+            remove: PathBuf::from("/p/wt"),
+            status,
+        }
+    }
+
+    #[test]
+    fn skip_reason_keeps_directories_unless_allowed() {
+        let stale = candidate(PruneKind::StaleAdmin, None);
+        let clean = candidate(PruneKind::Orphaned, Some(WorktreeStatus::Clean));
+        let dirty = candidate(PruneKind::Orphaned, Some(WorktreeStatus::Dirty));
+        let broken = candidate(PruneKind::BrokenLink, None);
+
+        assert_eq!(stale.skip_reason(false, true), None);
+        assert!(clean.skip_reason(false, true).is_some());
+        assert_eq!(clean.skip_reason(true, true), None);
+        assert_eq!(dirty.skip_reason(true, true), Some("uncommitted changes"));
+        assert!(broken.skip_reason(true, true).is_some());
+        // Picked by hand in the picker
+        assert_eq!(dirty.skip_reason(true, false), None);
+        assert_eq!(broken.skip_reason(true, false), None);
+    }
+
+    #[test]
+    fn parse_dot_git_file_valid() {
+        assert_eq!(
+            parse_dot_git_file("gitdir: /p/repo.git/worktrees/main\n"),
+            Some(PathBuf::from("/p/repo.git/worktrees/main"))
+        );
+    }
+
+    #[test]
+    fn parse_dot_git_file_garbage() {
+        assert_eq!(parse_dot_git_file("not a git file"), None);
+        assert_eq!(parse_dot_git_file("gitdir:   \n"), None);
+    }
+
+    #[test]
+    fn parse_admin_gitdir_valid() {
+        assert_eq!(
+            parse_admin_gitdir("/p/proj_main/.git\n"),
+            Some(PathBuf::from("/p/proj_main"))
+        );
+    }
+
+    #[test]
+    fn parse_admin_gitdir_garbage() {
+        assert_eq!(parse_admin_gitdir(""), None);
+        assert_eq!(parse_admin_gitdir("/p/proj_main"), None);
+    }
+}
@@ -1,4 +1,8 @@
-use std::io::{self, Write};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
 
 use console::style;
 use crossterm::event::{self, Event};
@@ -7,13 +11,18 @@ use ratatui::layout::Rect;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
-use crate::cli::RemoveMatchMode;
-use crate::git::repo::find_bare_repo;
+use crate::cli::MatchMode;
+use crate::commands::list::{self, WorktreeStatus};
+use crate::config::{GrovConfig, read_config};
+use crate::git::executor::run_git_ok;
+use crate::git::repo::{default_branch, find_bare_repo};
 use crate::git::status::is_dirty;
 use crate::git::worktree::{
-    WorktreeInfo, delete_branch, list_worktrees, matches_branch_name, matches_dir_name,
-    remove_worktree, safe_delete_branch, worktree_dir_name,
+    WorktreeInfo, delete_branch, gone_branches, list_worktrees, matches_branch_name,
+    matches_dir_name, merged_branches, merged_into_default, remove_worktree, safe_delete_branch,
+    worktree_dir_name,
 };
+use crate::hooks::{Hook, HookContext, run_hook, run_post_hook};
 use crate::tui::FlowOutcome;
 use crate::tui::confirm::{self, Confirm};
 use crate::tui::select_list::{self, SelectList, SelectResult};
@@ -22,20 +31,24 @@ use crate::tui::theme;
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct RemoveChoice {
-    pub worktree_index: usize,
+    /// Indices into the worktree list, in list order.
+    pub worktree_indices: Vec<usize>,
     pub delete_branch: bool,
 }
 
 pub fn execute(
     name: Option<&str>,
-    match_mode: RemoveMatchMode,
+    match_mode: MatchMode,
     do_delete_branch: bool,
     force: bool,
+    allow_protected: bool,
+    no_hooks: bool,
 ) -> anyhow::Result<()> {
     use std::io::IsTerminal;
 
     let cwd = std::env::current_dir()?;
     let repo = find_bare_repo(&cwd)?;
+    let config = read_config(&repo)?;
     let worktrees = list_worktrees(&repo)?;
     let is_tty = std::io::stdin().is_terminal();
 
@@ -49,8 +62,24 @@ pub fn execute(
             if !is_tty {
                 anyhow::bail!("worktree name is required when stdin is not a terminal");
             }
-            let choice = resolve_by_picker(&worktrees, do_delete_branch)?;
-            (choice.worktree_index, choice.delete_branch)
+            let choice = resolve_by_picker(
+                &repo,
+                &config,
+                &cwd,
+                &worktrees,
+                force,
+                allow_protected,
+                do_delete_branch,
+            )?;
+            return remove_picked(
+                &repo,
+                &config,
+                &worktrees,
+                choice,
+                force,
+                allow_protected,
+                no_hooks,
+            );
         }
     };
     let wt = &worktrees[wt_index];
@@ -58,6 +87,7 @@ pub fn execute(
     if wt.is_bare {
         anyhow::bail!("cannot remove the bare repository entry");
     }
+    check_removable(&repo, &config, wt, allow_protected)?;
 
     // Check for dirty state
     if !force && is_dirty(&wt.path).unwrap_or(false) {
@@ -67,6 +97,15 @@ pub fn execute(
     let branch_name = wt.branch.clone();
     let wt_path = wt.path.clone();
 
+    let ctx = HookContext {
+        branch: branch_name.as_deref(),
+        worktree: &wt_path,
+        bare_repo: &repo,
+    };
+    if !no_hooks && wt_path.exists() {
+        run_hook(&config.hooks, Hook::PreRemove, &ctx, &wt_path).context("aborting removal")?;
+    }
+
     remove_worktree(&repo, &wt_path, force)?;
 
     println!(
@@ -88,24 +127,12 @@ pub fn execute(
                 );
             }
             Err(e) => {
-                let msg = format!("{e:#}");
-                if msg.contains("not fully merged") {
-                    // Inline y/n prompt (no full TUI — this is a follow-up to an error)
-                    eprint!(
-                        "{} Branch has unmerged changes. Force delete? [y/N] ",
-                        style("!").yellow().bold()
-                    );
-                    io::stderr().flush()?;
-                    let mut answer = String::new();
-                    io::stdin().read_line(&mut answer)?;
-                    if answer.trim().eq_ignore_ascii_case("y") {
-                        delete_branch(&repo, branch)?;
-                        println!(
-                            "{} Deleted branch {}",
-                            style("\u{2713}").green().bold(),
-                            style(branch).cyan().bold(),
-                        );
-                    }
+                if is_not_fully_merged(&e) {
+                    force_delete_unmerged(
+                        &repo,
+                        std::slice::from_ref(branch),
+                        &mut io::stdin().lock(),
+                    )?;
                 } else {
                     eprintln!(
                         "{} Could not delete branch: {e:#}",
@@ -116,24 +143,281 @@ pub fn execute(
         }
     }
 
+    if !no_hooks {
+        run_post_hook(&config.hooks, Hook::PostRemove, &ctx, project_dir(&repo));
+    }
+
+    Ok(())
+}
+
+/// Why a worktree may not be removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Blocker {
+    Locked,
+    Protected,
+}
+
+impl Blocker {
+    fn label(self) -> &'static str {
+        match self {
+            Blocker::Locked => "locked",
+            Blocker::Protected => "protected",
+        }
+    }
+
+    fn skip_reason(self) -> &'static str {
+        match self {
+            Blocker::Locked => "locked (run `grov unlock` first)",
+            Blocker::Protected => "protected (use --allow-protected)",
+        }
+    }
+}
+
+/// Locked worktrees, and protected branches unless `allow_protected`.
+fn blocker(
+    config: &GrovConfig,
+    wt: &WorktreeInfo,
+    default: Option<&str>,
+    allow_protected: bool,
+) -> Option<Blocker> {
+    if wt.locked.is_some() {
+        return Some(Blocker::Locked);
+    }
+    let branch = wt.branch.as_deref()?;
+    (!allow_protected && config.worktree.is_protected(branch, default))
+        .then_some(Blocker::Protected)
+}
+
+/// Refuse locked worktrees, and protected branches unless `allow_protected`.
+fn check_removable(
+    repo: &Path,
+    config: &GrovConfig,
+    wt: &WorktreeInfo,
+    allow_protected: bool,
+) -> anyhow::Result<()> {
+    let default = default_branch(repo).ok();
+    match blocker(config, wt, default.as_deref(), allow_protected) {
+        Some(Blocker::Locked) => {
+            let reason = match wt.locked.as_deref() {
+                Some(reason) if !reason.is_empty() => format!(" ({reason})"),
+                _ => String::new(),
+            };
+            anyhow::bail!(
+                "worktree at {} is locked{reason}; run `grov unlock {}` first",
+                wt.path.display(),
+                worktree_dir_name(wt)
+            );
+        }
+        Some(Blocker::Protected) => anyhow::bail!(
+            "branch {} is protected (worktree.protected); pass --allow-protected to remove it anyway",
+            wt.branch.as_deref().unwrap_or_default()
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Directory that holds `repo.git` and its worktrees; post-remove hooks run here.
+fn project_dir(repo: &Path) -> &Path {
+    repo.parent().expect("bare repo must have a parent dir")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MergedReason {
+    Merged,
+    Gone,
+}
+
+/// Remove every worktree whose branch is merged into the default branch or
+/// whose upstream is gone, deleting the local branches too.
+pub fn execute_merged(
+    force: bool,
+    yes: bool,
+    allow_protected: bool,
+    no_hooks: bool,
+) -> anyhow::Result<()> {
+    use std::io::IsTerminal;
+
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+
+    // Fetch with prune so deleted remote branches show up as gone
+    if let Err(err) = run_git_ok(Some(&repo), &["fetch", "--prune", "origin"]) {
+        eprintln!(
+            "{} could not fetch from origin: {err:#}; continuing with local refs",
+            style("warning:").yellow().bold()
+        );
+    }
+
+    let default = default_branch(&repo)?;
+    let merged = merged_into_default(&repo, &default)?;
+    let gone = gone_branches(&repo)?;
+    let worktrees = list_worktrees(&repo)?;
+    let config = read_config(&repo)?;
+
+    let mut candidates = Vec::new();
+    let mut skipped_dirty = Vec::new();
+    for (i, reason) in merged_candidates(&worktrees, &merged, &gone, &default) {
+        if let Err(err) = check_removable(&repo, &config, &worktrees[i], allow_protected) {
+            eprintln!("{} Skipping: {err:#}", style("!").yellow().bold());
+            continue;
+        }
+        if !force && is_dirty(&worktrees[i].path).unwrap_or(false) {
+            skipped_dirty.push(i);
+        } else {
+            candidates.push((i, reason));
+        }
+    }
+
+    for &i in &skipped_dirty {
+        eprintln!(
+            "{} Skipping {}: uncommitted changes (use --force to include)",
+            style("!").yellow().bold(),
+            style(worktrees[i].branch.as_deref().unwrap_or_default()).cyan(),
+        );
+    }
+
+    if candidates.is_empty() {
+        println!("{}", style("No merged worktrees to remove.").dim());
+        return Ok(());
+    }
+
+    let selected: Vec<(usize, MergedReason)> = if yes {
+        candidates
+    } else {
+        if !std::io::stdin().is_terminal() {
+            anyhow::bail!("--yes is required when stdin is not a terminal");
+        }
+        let items = candidates
+            .iter()
+            .map(|&(i, reason)| {
+                let wt = &worktrees[i];
+                let branch = wt.branch.as_deref().unwrap_or_default();
+                let reason = match reason {
+                    MergedReason::Merged => format!("merged into {default}"),
+                    MergedReason::Gone => "upstream gone".to_string(),
+                };
+                format!("{branch} ({})  {reason}", worktree_dir_name(wt))
+            })
+            .collect();
+        run_merged_picker(items)?
+            .into_iter()
+            .map(|i| candidates[i])
+            .collect()
+    };
+
+    if selected.is_empty() {
+        println!("{}", style("Nothing selected.").dim());
+        return Ok(());
+    }
+
+    let targets: Vec<usize> = selected.into_iter().map(|(i, _)| i).collect();
+    let outcome = remove_batch(&repo, &config, &worktrees, &targets, force, true, no_hooks);
+    for branch in &outcome.unmerged {
+        eprintln!(
+            "{} Kept branch {}: not fully merged",
+            style("!").yellow().bold(),
+            style(branch).cyan(),
+        );
+    }
+    let failed = outcome.failed;
+
+    let mut summary = outcome.summary();
+    if !skipped_dirty.is_empty() {
+        summary.push_str(&format!(", skipped {} dirty", skipped_dirty.len()));
+    }
+    if failed > 0 {
+        summary.push_str(&format!(", {failed} failed"));
+    }
+    println!("{summary}");
+
+    if failed > 0 {
+        anyhow::bail!("{failed} worktree(s) could not be removed");
+    }
     Ok(())
 }
 
+/// Pick worktrees whose branch is merged into `default` or has a gone upstream.
+///
+/// Skips the bare entry, detached and missing worktrees, and the default branch
+/// itself. Returns indices into `worktrees`.
+fn merged_candidates(
+    worktrees: &[WorktreeInfo],
+    merged: &[String],
+    gone: &[String],
+    default: &str,
+) -> Vec<(usize, MergedReason)> {
+    worktrees
+        .iter()
+        .enumerate()
+        .filter(|(_, wt)| !wt.is_bare && wt.path.exists())
+        .filter_map(|(i, wt)| {
+            let branch = wt.branch.as_deref()?;
+            if branch == default {
+                None
+            } else if merged.iter().any(|b| b == branch) {
+                Some((i, MergedReason::Merged))
+            } else if gone.iter().any(|b| b == branch) {
+                Some((i, MergedReason::Gone))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn run_merged_picker(items: Vec<String>) -> anyhow::Result<Vec<usize>> {
+    let all = 0..items.len();
+
+    run_tui(|terminal| {
+        let mut select = SelectList::new("Select merged worktrees to remove", items, vec![])
+            .with_multi_select(all);
+
+        loop {
+            terminal.draw(|frame| {
+                let area = frame.area();
+                let widget_area = Rect::new(0, 1, area.width, area.height.saturating_sub(3));
+                select.render(frame, widget_area);
+
+                let help_y = area.height.saturating_sub(1);
+                let help_line = Line::from(Span::styled(
+                    format!("  {}", theme::HELP_MULTI_SELECT),
+                    theme::DIM,
+                ));
+                frame.render_widget(
+                    Paragraph::new(help_line),
+                    Rect::new(0, help_y, area.width, 1),
+                );
+            })?;
+
+            if event::poll(std::time::Duration::from_millis(100))? {
+                match select.handle_event(&event::read()?) {
+                    select_list::Action::Selected(SelectResult::Items(indices)) => {
+                        return Ok(indices);
+                    }
+                    select_list::Action::Selected(_) => unreachable!(),
+                    select_list::Action::Cancel => anyhow::bail!("cancelled"),
+                    select_list::Action::Continue => {}
+                }
+            }
+        }
+    })
+}
+
 /// Resolve the worktree by name/match-mode. Returns the index into `worktrees`.
-fn resolve_by_name(
+pub(crate) fn resolve_by_name(
     worktrees: &[WorktreeInfo],
     name: &str,
-    match_mode: RemoveMatchMode,
+    match_mode: MatchMode,
 ) -> anyhow::Result<usize> {
     let matches: Vec<usize> = worktrees
         .iter()
         .enumerate()
         .filter(|(_, worktree)| match match_mode {
-            RemoveMatchMode::Auto => {
+            MatchMode::Auto => {
                 matches_branch_name(worktree, name) || matches_dir_name(worktree, name)
             }
-            RemoveMatchMode::Branch => matches_branch_name(worktree, name),
-            RemoveMatchMode::Dir => matches_dir_name(worktree, name),
+            MatchMode::Branch => matches_branch_name(worktree, name),
+            MatchMode::Dir => matches_dir_name(worktree, name),
         })
         .map(|(i, _)| i)
         .collect();
@@ -164,40 +448,84 @@ fn resolve_by_name(
 
 #[derive(Debug)]
 enum PickerStep {
-    SelectWorktree,
-    ConfirmDeleteBranch { wt_index: usize },
+    SelectWorktrees,
+    ConfirmDeleteBranch {
+        selected: Vec<usize>,
+    },
+    ConfirmRemoval {
+        selected: Vec<usize>,
+        delete_branch: bool,
+    },
+}
+
+/// What the remove picker knows about the worktrees before anything is confirmed.
+#[derive(Debug, Default)]
+pub(crate) struct RemovalFacts {
+    /// Dirty worktrees, and those whose status could not be read.
+    pub at_risk: HashMap<PathBuf, WorktreeStatus>,
+    /// Locked worktrees and protected branches, which are never removed.
+    pub blocked: HashMap<PathBuf, Blocker>,
+    /// Local branches merged into HEAD; `git branch -d` keeps the others
+    /// unless their upstream has them.
+    pub merged: HashSet<String>,
 }
 
 pub(crate) struct RemovePicker {
     step: PickerStep,
-    candidates: Vec<(usize, String)>,
+    candidates: Vec<usize>,
     worktrees: Vec<WorktreeInfo>,
+    facts: RemovalFacts,
+    force: bool,
     flag_delete_branch: bool,
     select: SelectList,
     confirm: Confirm,
 }
 
 impl RemovePicker {
-    pub(crate) fn new(worktrees: Vec<WorktreeInfo>, flag_delete_branch: bool) -> Self {
-        let candidates: Vec<(usize, String)> = worktrees
+    /// Blocked worktrees are always skipped. At-risk ones are skipped without
+    /// `force`; with it the final confirmation warns that their changes will
+    /// be lost.
+    pub(crate) fn new(
+        worktrees: Vec<WorktreeInfo>,
+        flag_delete_branch: bool,
+        facts: RemovalFacts,
+        force: bool,
+    ) -> Self {
+        let candidates: Vec<usize> = worktrees
             .iter()
             .enumerate()
             .filter(|(_, wt)| !wt.is_bare)
-            .map(|(i, wt)| {
-                let branch = wt.branch.as_deref().unwrap_or("<detached>");
-                let dir = worktree_dir_name(wt);
-                (i, format!("{branch} ({dir})"))
-            })
+            .map(|(i, _)| i)
             .collect();
 
-        let display: Vec<String> = candidates.iter().map(|(_, label)| label.clone()).collect();
-        let select = SelectList::new("Select a worktree to remove", display, vec![]);
+        let display: Vec<String> = candidates
+            .iter()
+            .map(|&i| worktree_label(&worktrees[i]))
+            .collect();
+        let hints = candidates
+            .iter()
+            .map(|&i| {
+                let path = &worktrees[i].path;
+                let hint = match (facts.blocked.get(path), facts.at_risk.get(path)) {
+                    (Some(blocker), _) => blocker.label(),
+                    (None, Some(WorktreeStatus::Unknown)) => "status unknown",
+                    (None, Some(_)) => "dirty",
+                    (None, None) => return vec![],
+                };
+                vec![Span::styled(hint, theme::WARNING)]
+            })
+            .collect();
+        let select = SelectList::new("Select worktrees to remove", display, vec![])
+            .with_multi_select([])
+            .with_hints(hints);
         let confirm = Confirm::new("");
 
         Self {
-            step: PickerStep::SelectWorktree,
+            step: PickerStep::SelectWorktrees,
             candidates,
             worktrees,
+            facts,
+            force,
             flag_delete_branch,
             select,
             confirm,
@@ -209,76 +537,201 @@ impl RemovePicker {
         event: &Event,
     ) -> anyhow::Result<FlowOutcome<RemoveChoice>> {
         match &self.step {
-            PickerStep::SelectWorktree => match self.select.handle_event(event) {
-                select_list::Action::Selected(SelectResult::Item(i)) => {
-                    let wt_index = self.candidates[i].0;
-                    let wt = &self.worktrees[wt_index];
-
-                    // If --delete-branch flag was passed, skip confirm
-                    if self.flag_delete_branch {
-                        return Ok(FlowOutcome::Done(RemoveChoice {
-                            worktree_index: wt_index,
-                            delete_branch: true,
-                        }));
+            PickerStep::SelectWorktrees => match self.select.handle_event(event) {
+                select_list::Action::Selected(SelectResult::Items(mut checked)) => {
+                    // Enter with nothing checked removes the highlighted worktree
+                    if checked.is_empty() {
+                        checked.extend(self.select.highlighted());
+                    }
+                    if checked.is_empty() {
+                        return Ok(FlowOutcome::Continue);
                     }
+                    let selected: Vec<usize> =
+                        checked.into_iter().map(|i| self.candidates[i]).collect();
+                    // Nothing would be removed; the hints say why
+                    if self.removable(&selected).is_empty() {
+                        return Ok(FlowOutcome::Continue);
+                    }
+                    let branches = self.branches(&selected);
 
-                    // If worktree has a branch, ask about deletion
-                    if let Some(ref branch) = wt.branch {
-                        self.confirm = Confirm::new(format!("Delete branch '{branch}' too?"));
-                        self.step = PickerStep::ConfirmDeleteBranch { wt_index };
+                    if self.flag_delete_branch || branches.is_empty() {
+                        let delete_branch = self.flag_delete_branch;
+                        self.confirm_removal(selected, delete_branch);
                     } else {
-                        return Ok(FlowOutcome::Done(RemoveChoice {
-                            worktree_index: wt_index,
-                            delete_branch: false,
-                        }));
+                        let label = match branches.as_slice() {
+                            [branch] => format!("Delete branch '{branch}' too?"),
+                            _ => format!("Delete their {} branches too?", branches.len()),
+                        };
+                        self.confirm = Confirm::new(label);
+                        self.step = PickerStep::ConfirmDeleteBranch { selected };
                     }
                 }
-                select_list::Action::Selected(SelectResult::Extra(_)) => unreachable!(),
+                select_list::Action::Selected(SelectResult::Item(_) | SelectResult::Extra(_)) => {
+                    unreachable!()
+                }
                 select_list::Action::Cancel => anyhow::bail!("cancelled"),
                 select_list::Action::Continue => {}
             },
-            PickerStep::ConfirmDeleteBranch { wt_index } => {
-                let wt_index = *wt_index;
+            PickerStep::ConfirmDeleteBranch { selected } => {
                 match self.confirm.handle_event(event) {
                     confirm::Action::Confirmed(yes) => {
-                        return Ok(FlowOutcome::Done(RemoveChoice {
-                            worktree_index: wt_index,
-                            delete_branch: yes,
-                        }));
-                    }
-                    confirm::Action::Cancel => {
-                        let display: Vec<String> = self
-                            .candidates
-                            .iter()
-                            .map(|(_, label)| label.clone())
-                            .collect();
-                        self.select =
-                            SelectList::new("Select a worktree to remove", display, vec![]);
-                        self.step = PickerStep::SelectWorktree;
+                        let selected = selected.clone();
+                        self.confirm_removal(selected, yes);
                     }
+                    confirm::Action::Cancel => self.step = PickerStep::SelectWorktrees,
                     confirm::Action::Continue => {}
                 }
             }
+            PickerStep::ConfirmRemoval {
+                selected,
+                delete_branch,
+            } => match self.confirm.handle_event(event) {
+                confirm::Action::Confirmed(true) => {
+                    return Ok(FlowOutcome::Done(RemoveChoice {
+                        worktree_indices: self.removable(selected),
+                        delete_branch: *delete_branch,
+                    }));
+                }
+                // The list keeps its checks, so the selection can be adjusted
+                confirm::Action::Confirmed(false) | confirm::Action::Cancel => {
+                    self.step = PickerStep::SelectWorktrees;
+                }
+                confirm::Action::Continue => {}
+            },
         }
         Ok(FlowOutcome::Continue)
     }
 
+    /// Why the worktree at `i` will not be removed, if it will not.
+    fn skip_reason(&self, i: usize) -> Option<&'static str> {
+        let path = &self.worktrees[i].path;
+        if let Some(blocker) = self.facts.blocked.get(path) {
+            return Some(blocker.skip_reason());
+        }
+        if self.force {
+            return None;
+        }
+        match self.facts.at_risk.get(path)? {
+            WorktreeStatus::Unknown => Some("status unknown (use --force)"),
+            _ => Some("uncommitted changes (use --force)"),
+        }
+    }
+
+    fn removable(&self, selected: &[usize]) -> Vec<usize> {
+        selected
+            .iter()
+            .copied()
+            .filter(|&i| self.skip_reason(i).is_none())
+            .collect()
+    }
+
+    /// Branches of the worktrees in `selected` that will be removed.
+    fn branches(&self, selected: &[usize]) -> Vec<&str> {
+        self.removable(selected)
+            .into_iter()
+            .filter_map(|i| self.worktrees[i].branch.as_deref())
+            .collect()
+    }
+
+    fn confirm_removal(&mut self, selected: Vec<usize>, delete_branch: bool) {
+        let label = match self.removable(&selected).len() {
+            1 => "Remove 1 worktree?".to_string(),
+            n => format!("Remove {n} worktrees?"),
+        };
+        self.confirm = Confirm::new(label);
+        self.step = PickerStep::ConfirmRemoval {
+            selected,
+            delete_branch,
+        };
+    }
+
+    /// Lines listing what the final confirmation will remove.
+    fn removal_summary(&self, selected: &[usize], delete_branch: bool) -> Vec<Line<'_>> {
+        let mut lines = vec![Line::from(Span::styled("  Worktrees:", theme::DIM))];
+        for i in self.removable(selected) {
+            let wt = &self.worktrees[i];
+            let mut spans = vec![Span::raw(format!("    {}", worktree_label(wt)))];
+            let warning = match self.facts.at_risk.get(&wt.path) {
+                Some(WorktreeStatus::Unknown) => "  status unknown; changes may be lost",
+                Some(_) => "  uncommitted changes will be lost",
+                None => "",
+            };
+            if !warning.is_empty() {
+                spans.push(Span::styled(warning, theme::WARNING));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let skipped: Vec<(usize, &str)> = selected
+            .iter()
+            .filter_map(|&i| self.skip_reason(i).map(|reason| (i, reason)))
+            .collect();
+        if !skipped.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled("  Skipped:", theme::DIM)));
+            for (i, reason) in skipped {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("    {}", worktree_label(&self.worktrees[i]))),
+                    Span::styled(format!("  {reason}"), theme::DIM),
+                ]));
+            }
+        }
+
+        let branches = self.branches(selected);
+        if delete_branch && !branches.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                "  Branches to delete:",
+                theme::DIM,
+            )));
+            for branch in branches {
+                let mut spans = vec![Span::styled(format!("    {branch}"), theme::HIGHLIGHT)];
+                if !self.facts.merged.contains(branch) {
+                    spans.push(Span::styled(
+                        "  unmerged, asks before force-deleting",
+                        theme::DIM,
+                    ));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+        lines
+    }
+
     pub(crate) fn render(&self, frame: &mut Frame) {
         let area = frame.area();
         let widget_area = Rect::new(0, 1, area.width, area.height.saturating_sub(3));
 
         match &self.step {
-            PickerStep::SelectWorktree => {
+            PickerStep::SelectWorktrees => {
                 self.select.render(frame, widget_area);
             }
             PickerStep::ConfirmDeleteBranch { .. } => {
                 self.confirm.render(frame, widget_area);
             }
+            PickerStep::ConfirmRemoval {
+                selected,
+                delete_branch,
+            } => {
+                self.confirm.render(frame, widget_area);
+                let summary_area = Rect::new(
+                    0,
+                    widget_area.y + 4,
+                    area.width,
+                    widget_area.height.saturating_sub(4),
+                );
+                frame.render_widget(
+                    Paragraph::new(self.removal_summary(selected, *delete_branch)),
+                    summary_area,
+                );
+            }
         }
 
         let help = match &self.step {
-            PickerStep::SelectWorktree => theme::HELP_SELECT,
-            PickerStep::ConfirmDeleteBranch { .. } => theme::HELP_CONFIRM,
+            PickerStep::SelectWorktrees => theme::HELP_MULTI_SELECT,
+            PickerStep::ConfirmDeleteBranch { .. } | PickerStep::ConfirmRemoval { .. } => {
+                theme::HELP_CONFIRM
+            }
         };
         let help_y = area.height.saturating_sub(1);
         let help_line = Line::from(Span::styled(format!("  {help}"), theme::DIM));
@@ -289,17 +742,30 @@ impl RemovePicker {
     }
 }
 
-/// Show an interactive TUI picker for worktree selection + optional branch deletion confirm.
+fn worktree_label(wt: &WorktreeInfo) -> String {
+    let branch = wt.branch.as_deref().unwrap_or("<detached>");
+    format!("{branch} ({})", worktree_dir_name(wt))
+}
+
+/// Show an interactive TUI picker for the worktrees to remove, an optional
+/// branch deletion confirm, and a final confirmation.
 fn resolve_by_picker(
+    repo: &Path,
+    config: &GrovConfig,
+    cwd: &Path,
     worktrees: &[WorktreeInfo],
+    force: bool,
+    allow_protected: bool,
     flag_delete_branch: bool,
 ) -> anyhow::Result<RemoveChoice> {
     if worktrees.iter().filter(|wt| !wt.is_bare).count() == 0 {
         anyhow::bail!("no worktrees to remove");
     }
 
+    let facts = removal_facts(repo, config, cwd, worktrees, allow_protected)?;
+
     run_tui(|terminal| {
-        let mut picker = RemovePicker::new(worktrees.to_vec(), flag_delete_branch);
+        let mut picker = RemovePicker::new(worktrees.to_vec(), flag_delete_branch, facts, force);
 
         loop {
             terminal.draw(|frame| picker.render(frame))?;
@@ -314,6 +780,260 @@ fn resolve_by_picker(
     })
 }
 
+/// Gather what the remove picker shows before confirming: blocked and
+/// at-risk worktrees, and which branches `git branch -d` would delete.
+fn removal_facts(
+    repo: &Path,
+    config: &GrovConfig,
+    cwd: &Path,
+    worktrees: &[WorktreeInfo],
+    allow_protected: bool,
+) -> anyhow::Result<RemovalFacts> {
+    let default = default_branch(repo).ok();
+    let blocked = worktrees
+        .iter()
+        .filter(|wt| !wt.is_bare)
+        .filter_map(|wt| {
+            blocker(config, wt, default.as_deref(), allow_protected)
+                .map(|blocker| (wt.path.clone(), blocker))
+        })
+        .collect();
+
+    // A status that could not be read may hide uncommitted changes
+    let at_risk = list::worktree_entries(repo, cwd, config.status.untracked)?
+        .into_iter()
+        .filter_map(|entry| match entry.status {
+            Some(status @ (WorktreeStatus::Dirty | WorktreeStatus::Unknown)) => {
+                Some((entry.worktree.path, status))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let merged = merged_branches(repo, "HEAD")
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+
+    Ok(RemovalFacts {
+        at_risk,
+        blocked,
+        merged,
+    })
+}
+
+/// Remove the worktrees confirmed in the picker.
+///
+/// Without `force` the picker left out worktrees that may hold uncommitted
+/// changes, and git still refuses any that became dirty since.
+fn remove_picked(
+    repo: &Path,
+    config: &GrovConfig,
+    worktrees: &[WorktreeInfo],
+    choice: RemoveChoice,
+    force: bool,
+    allow_protected: bool,
+    no_hooks: bool,
+) -> anyhow::Result<()> {
+    let mut targets = Vec::new();
+    for i in choice.worktree_indices {
+        match check_removable(repo, config, &worktrees[i], allow_protected) {
+            Ok(()) => targets.push(i),
+            Err(err) => eprintln!("{} Skipping: {err:#}", style("!").yellow().bold()),
+        }
+    }
+    if targets.is_empty() {
+        anyhow::bail!("no removable worktrees selected");
+    }
+
+    let mut outcome = remove_batch(
+        repo,
+        config,
+        worktrees,
+        &targets,
+        force,
+        choice.delete_branch,
+        no_hooks,
+    );
+    outcome.deleted += force_delete_unmerged(repo, &outcome.unmerged, &mut io::stdin().lock())?;
+    if targets.len() > 1 {
+        let mut summary = outcome.summary();
+        if outcome.failed > 0 {
+            summary.push_str(&format!(", {} failed", outcome.failed));
+        }
+        println!("{summary}");
+    }
+
+    if outcome.failed > 0 {
+        anyhow::bail!("{} worktree(s) could not be removed", outcome.failed);
+    }
+    Ok(())
+}
+
+/// Counts reported by [`remove_batch`].
+struct BatchOutcome {
+    removed: usize,
+    deleted: usize,
+    failed: usize,
+    /// Branches `git branch -d` kept because they are not fully merged.
+    unmerged: Vec<String>,
+}
+
+impl BatchOutcome {
+    fn summary(&self) -> String {
+        format!(
+            "\nRemoved {} worktree{}, deleted {} branch{}",
+            self.removed,
+            if self.removed == 1 { "" } else { "s" },
+            self.deleted,
+            if self.deleted == 1 { "" } else { "es" },
+        )
+    }
+}
+
+/// Remove each worktree in `targets`, running hooks and deleting merged local
+/// branches when `delete_branch` is set. Failures are reported and counted
+/// rather than stopping the batch.
+fn remove_batch(
+    repo: &Path,
+    config: &GrovConfig,
+    worktrees: &[WorktreeInfo],
+    targets: &[usize],
+    force: bool,
+    delete_branch: bool,
+    no_hooks: bool,
+) -> BatchOutcome {
+    let mut outcome = BatchOutcome {
+        removed: 0,
+        deleted: 0,
+        failed: 0,
+        unmerged: Vec::new(),
+    };
+    for &i in targets {
+        let wt = &worktrees[i];
+        let ctx = HookContext {
+            branch: wt.branch.as_deref(),
+            worktree: &wt.path,
+            bare_repo: repo,
+        };
+
+        if !no_hooks
+            && wt.path.exists()
+            && let Err(e) = run_hook(&config.hooks, Hook::PreRemove, &ctx, &wt.path)
+        {
+            eprintln!(
+                "{} Kept {}: {e:#}",
+                style("!").yellow().bold(),
+                wt.path.display()
+            );
+            outcome.failed += 1;
+            continue;
+        }
+
+        if let Err(e) = remove_worktree(repo, &wt.path, force) {
+            eprintln!(
+                "{} Could not remove {}: {e:#}",
+                style("!").yellow().bold(),
+                wt.path.display()
+            );
+            outcome.failed += 1;
+            continue;
+        }
+        outcome.removed += 1;
+        println!(
+            "{} Removed worktree at {}",
+            style("\u{2713}").green().bold(),
+            style(wt.path.display()).bold(),
+        );
+
+        if let Some(branch) = wt.branch.as_deref()
+            && delete_branch
+        {
+            match safe_delete_branch(repo, branch) {
+                Ok(()) => {
+                    outcome.deleted += 1;
+                    println!(
+                        "{} Deleted branch {}",
+                        style("\u{2713}").green().bold(),
+                        style(branch).cyan().bold(),
+                    );
+                }
+                // Left to the caller, which may offer to force-delete
+                Err(e) if is_not_fully_merged(&e) => outcome.unmerged.push(branch.to_string()),
+                Err(e) => eprintln!(
+                    "{} Kept branch {}: {e:#}",
+                    style("!").yellow().bold(),
+                    style(branch).cyan(),
+                ),
+            }
+        }
+
+        if !no_hooks {
+            run_post_hook(&config.hooks, Hook::PostRemove, &ctx, project_dir(repo));
+        }
+    }
+    outcome
+}
+
+fn is_not_fully_merged(err: &crate::errors::GrovError) -> bool {
+    format!("{err:#}").contains("not fully merged")
+}
+
+/// Ask once whether to force-delete `branches`, which `git branch -d` kept
+/// because they are not fully merged. Returns how many were deleted.
+fn force_delete_unmerged(
+    repo: &Path,
+    branches: &[String],
+    input: &mut impl BufRead,
+) -> anyhow::Result<usize> {
+    // Inline y/n prompt (no full TUI — this is a follow-up to an error)
+    match branches {
+        [] => return Ok(0),
+        [_] => eprint!(
+            "{} Branch has unmerged changes. Force delete? [y/N] ",
+            style("!").yellow().bold()
+        ),
+        _ => eprint!(
+            "{} Branches {} have unmerged changes. Force delete them? [y/N] ",
+            style("!").yellow().bold(),
+            branches.join(", ")
+        ),
+    }
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    if !answer.trim().eq_ignore_ascii_case("y") {
+        for branch in branches {
+            eprintln!(
+                "{} Kept branch {}",
+                style("!").yellow().bold(),
+                style(branch).cyan()
+            );
+        }
+        return Ok(0);
+    }
+
+    let mut deleted = 0;
+    for branch in branches {
+        match delete_branch(repo, branch) {
+            Ok(()) => {
+                deleted += 1;
+                println!(
+                    "{} Deleted branch {}",
+                    style("\u{2713}").green().bold(),
+                    style(branch).cyan().bold(),
+                );
+            }
+            Err(e) => eprintln!(
+                "{} Could not delete branch {}: {e:#}",
+                style("!").yellow().bold(),
+                style(branch).cyan(),
+            ),
+        }
+    }
+    Ok(deleted)
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -341,125 +1061,356 @@ mod tests {
                 head: "abc1234".to_string(),
                 branch: None,
                 is_bare: true,
+                locked: None,
+                prunable: None,
             },
             WorktreeInfo {
                 path: PathBuf::from("/project/dev_main"),
                 head: "def5678".to_string(),
                 branch: Some("main".to_string()),
                 is_bare: false,
+                locked: None,
+                prunable: None,
             },
             WorktreeInfo {
                 path: PathBuf::from("/project/dev_feature"),
                 head: "ghi9012".to_string(),
                 branch: Some("feature".to_string()),
                 is_bare: false,
+                locked: None,
+                prunable: None,
             },
             WorktreeInfo {
                 path: PathBuf::from("/project/dev_detached"),
                 head: "jkl3456".to_string(),
                 branch: None,
                 is_bare: false,
+                locked: None,
+                prunable: None,
             },
         ]
     }
 
     #[test]
-    fn select_with_branch_shows_confirm() {
-        let mut picker = RemovePicker::new(make_worktrees(), false);
-        // First non-bare is index 0 in candidates (worktree index 1, "main")
-        // Press Enter to select it
+    fn merged_candidates_skips_default_detached_and_unmerged() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut worktrees = make_worktrees();
+        for wt in &mut worktrees {
+            wt.path = tmp.path().to_path_buf();
+        }
+        worktrees.push(WorktreeInfo {
+            path: tmp.path().to_path_buf(),
+            head: "mno7890".to_string(),
+            branch: Some("stale".to_string()),
+            is_bare: false,
+            locked: None,
+            prunable: None,
+        });
+        worktrees.push(WorktreeInfo {
+            path: tmp.path().to_path_buf(),
+            head: "pqr1234".to_string(),
+            branch: Some("wip".to_string()),
+            is_bare: false,
+            locked: None,
+            prunable: None,
+        });
+
+        let merged = vec!["main".to_string(), "feature".to_string()];
+        let gone = vec!["stale".to_string()];
+        assert_eq!(
+            merged_candidates(&worktrees, &merged, &gone, "main"),
+            vec![(2, MergedReason::Merged), (4, MergedReason::Gone)]
+        );
+    }
+
+    #[test]
+    fn unmerged_branches_are_force_deleted_only_on_yes() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(["-c", "user.name=T", "-c", "user.email=t@t"])
+                .args(args)
+                .current_dir(tmp.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "initial"]);
+        git(&["checkout", "-q", "-b", "topic"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "work"]);
+        git(&["checkout", "-q", "main"]);
+        let repo = tmp.path().join(".git");
+        let topic = vec!["topic".to_string()];
+
+        let err = safe_delete_branch(&repo, "topic").unwrap_err();
+        assert!(is_not_fully_merged(&err), "{err}");
+        assert_eq!(
+            force_delete_unmerged(&repo, &topic, &mut "\n".as_bytes()).unwrap(),
+            0
+        );
+        assert!(crate::git::worktree::branch_exists_local(&repo, "topic"));
+
+        assert_eq!(
+            force_delete_unmerged(&repo, &topic, &mut "y\n".as_bytes()).unwrap(),
+            1
+        );
+        assert!(!crate::git::worktree::branch_exists_local(&repo, "topic"));
+    }
+
+    #[test]
+    fn merged_candidates_skips_missing_worktrees() {
+        let merged = vec!["feature".to_string()];
+        // make_worktrees() paths do not exist on disk
+        assert!(merged_candidates(&make_worktrees(), &merged, &[], "main").is_empty());
+    }
+
+    fn facts() -> RemovalFacts {
+        RemovalFacts {
+            at_risk: HashMap::from([
+                (PathBuf::from("/project/dev_feature"), WorktreeStatus::Dirty),
+                (
+                    PathBuf::from("/project/dev_detached"),
+                    WorktreeStatus::Unknown,
+                ),
+            ]),
+            blocked: HashMap::new(),
+            merged: HashSet::from(["main".to_string()]),
+        }
+    }
+
+    /// A picker run with `--force`, so nothing is skipped.
+    fn picker(flag_delete_branch: bool) -> RemovePicker {
+        RemovePicker::new(make_worktrees(), flag_delete_branch, facts(), true)
+    }
+
+    fn space() -> Event {
+        key_char(' ')
+    }
+
+    fn done(result: FlowOutcome<RemoveChoice>) -> RemoveChoice {
+        match result {
+            FlowOutcome::Done(choice) => choice,
+            FlowOutcome::Continue => panic!("expected Done"),
+        }
+    }
+
+    #[test]
+    fn enter_without_checks_picks_highlighted() {
+        let mut picker = picker(false);
+        // Nothing checked: Enter takes the highlighted worktree (main)
         let result = feed_picker(&mut picker, &[enter()]).unwrap();
-        // Should move to confirm step, not return Done yet
         assert!(matches!(result, FlowOutcome::Continue));
-        assert!(matches!(
-            picker.step,
-            PickerStep::ConfirmDeleteBranch { .. }
-        ));
+        match &picker.step {
+            PickerStep::ConfirmDeleteBranch { selected } => assert_eq!(selected, &[1]),
+            step => panic!("unexpected step {step:?}"),
+        }
     }
 
     #[test]
-    fn select_without_branch_returns() {
-        let mut picker = RemovePicker::new(make_worktrees(), false);
-        // Navigate down to the detached worktree (3rd non-bare = index 2 in candidates)
+    fn select_without_branch_skips_branch_confirm() {
+        let mut picker = picker(false);
         let events = vec![
             key_event(crossterm::event::KeyCode::Down), // feature
             key_event(crossterm::event::KeyCode::Down), // detached
             enter(),
+            key_char('y'), // confirm removal
         ];
 
-        let result = feed_picker(&mut picker, &events).unwrap();
-        match result {
-            FlowOutcome::Done(choice) => {
-                assert_eq!(choice.worktree_index, 3);
-                assert!(!choice.delete_branch);
-            }
-            FlowOutcome::Continue => panic!("expected Done"),
-        }
+        let choice = done(feed_picker(&mut picker, &events).unwrap());
+        assert_eq!(choice.worktree_indices, vec![3]);
+        assert!(!choice.delete_branch);
     }
 
     #[test]
-    fn flag_delete_branch_skips_confirm() {
-        let mut picker = RemovePicker::new(make_worktrees(), true);
-        // Select first (main) — should skip confirm because flag is set
+    fn flag_delete_branch_skips_branch_confirm() {
+        let mut picker = picker(true);
         let result = feed_picker(&mut picker, &[enter()]).unwrap();
-        match result {
-            FlowOutcome::Done(choice) => {
-                assert_eq!(choice.worktree_index, 1);
-                assert!(choice.delete_branch);
+        assert!(matches!(result, FlowOutcome::Continue));
+        assert!(matches!(
+            picker.step,
+            PickerStep::ConfirmRemoval {
+                delete_branch: true,
+                ..
             }
-            FlowOutcome::Continue => panic!("expected Done"),
-        }
+        ));
+
+        let choice = done(feed_picker(&mut picker, &[key_char('y')]).unwrap());
+        assert_eq!(choice.worktree_indices, vec![1]);
+        assert!(choice.delete_branch);
     }
 
     #[test]
-    fn confirm_yes() {
-        let mut picker = RemovePicker::new(make_worktrees(), false);
-        // Select main, then confirm with 'y'
+    fn batch_selection_confirms_once() {
+        let mut picker = picker(false);
         let events = vec![
-            enter(),       // select main
-            key_char('y'), // confirm yes
+            space(), // check main
+            key_event(crossterm::event::KeyCode::Down),
+            space(), // check feature
+            enter(),
+            key_char('y'), // delete branches
+            key_char('y'), // remove
         ];
 
-        let result = feed_picker(&mut picker, &events).unwrap();
-        match result {
-            FlowOutcome::Done(choice) => {
-                assert_eq!(choice.worktree_index, 1);
-                assert!(choice.delete_branch);
-            }
-            FlowOutcome::Continue => panic!("expected Done"),
-        }
+        let choice = done(feed_picker(&mut picker, &events).unwrap());
+        assert_eq!(choice.worktree_indices, vec![1, 2]);
+        assert!(choice.delete_branch);
     }
 
     #[test]
-    fn confirm_no() {
-        let mut picker = RemovePicker::new(make_worktrees(), false);
-        // Select main, then confirm with 'n'
-        let events = vec![
-            enter(),       // select main
-            key_char('n'), // confirm no
-        ];
+    fn declining_branch_deletion_still_removes() {
+        let mut picker = picker(false);
+        let events = vec![key_char('a'), enter(), key_char('n'), key_char('y')];
 
-        let result = feed_picker(&mut picker, &events).unwrap();
-        match result {
-            FlowOutcome::Done(choice) => {
-                assert_eq!(choice.worktree_index, 1);
-                assert!(!choice.delete_branch);
-            }
-            FlowOutcome::Continue => panic!("expected Done"),
-        }
+        let choice = done(feed_picker(&mut picker, &events).unwrap());
+        assert_eq!(choice.worktree_indices, vec![1, 2, 3]);
+        assert!(!choice.delete_branch);
     }
 
     #[test]
-    fn esc_from_confirm_returns_to_list() {
-        let mut picker = RemovePicker::new(make_worktrees(), false);
-        // Select main, then Esc from confirm
+    fn esc_from_branch_confirm_returns_to_list() {
+        let mut picker = picker(false);
+        let result = feed_picker(&mut picker, &[enter(), esc()]).unwrap();
+        assert!(matches!(result, FlowOutcome::Continue));
+        assert!(matches!(picker.step, PickerStep::SelectWorktrees));
+    }
+
+    #[test]
+    fn declining_removal_keeps_checks() {
+        let mut picker = picker(true);
+        let events = vec![
+            space(), // check main
+            enter(),
+            key_char('n'), // back to the list
+        ];
+        let result = feed_picker(&mut picker, &events).unwrap();
+        assert!(matches!(result, FlowOutcome::Continue));
+        assert!(matches!(picker.step, PickerStep::SelectWorktrees));
+
+        // Check feature as well; main is still checked
         let events = vec![
-            enter(), // select main
-            esc(),   // back from confirm
+            key_event(crossterm::event::KeyCode::Down),
+            space(),
+            enter(),
+            key_char('y'),
         ];
+        let choice = done(feed_picker(&mut picker, &events).unwrap());
+        assert_eq!(choice.worktree_indices, vec![1, 2]);
+    }
+
+    #[test]
+    fn removal_summary_lists_dirty_worktrees_and_branches() {
+        let picker = picker(false);
+        let text: Vec<String> = picker
+            .removal_summary(&[1, 2, 3], true)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            text,
+            vec![
+                "  Worktrees:",
+                "    main (dev_main)",
+                "    feature (dev_feature)  uncommitted changes will be lost",
+                "    <detached> (dev_detached)  status unknown; changes may be lost",
+                "",
+                "  Branches to delete:",
+                "    main",
+                "    feature  unmerged, asks before force-deleting",
+            ]
+        );
+    }
 
+    #[test]
+    fn without_force_skips_dirty_and_unknown_worktrees() {
+        let mut picker = RemovePicker::new(make_worktrees(), true, facts(), false);
+        let events = vec![key_char('a'), enter()];
+        feed_picker(&mut picker, &events).unwrap();
+
+        let text: Vec<String> = picker
+            .removal_summary(&[1, 2, 3], true)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            text,
+            vec![
+                "  Worktrees:",
+                "    main (dev_main)",
+                "",
+                "  Skipped:",
+                "    feature (dev_feature)  uncommitted changes (use --force)",
+                "    <detached> (dev_detached)  status unknown (use --force)",
+                "",
+                "  Branches to delete:",
+                "    main",
+            ]
+        );
+
+        let choice = done(feed_picker(&mut picker, &[key_char('y')]).unwrap());
+        assert_eq!(choice.worktree_indices, vec![1]);
+    }
+
+    #[test]
+    fn without_force_enter_on_dirty_worktree_stays_in_list() {
+        let mut picker = RemovePicker::new(make_worktrees(), false, facts(), false);
+        let events = vec![key_event(crossterm::event::KeyCode::Down), enter()];
         let result = feed_picker(&mut picker, &events).unwrap();
         assert!(matches!(result, FlowOutcome::Continue));
-        assert!(matches!(picker.step, PickerStep::SelectWorktree));
+        assert!(matches!(picker.step, PickerStep::SelectWorktrees));
+    }
+
+    #[test]
+    fn blocked_worktrees_are_marked_and_skipped() {
+        let mut facts = facts();
+        facts
+            .blocked
+            .insert(PathBuf::from("/project/dev_main"), Blocker::Protected);
+        facts
+            .blocked
+            .insert(PathBuf::from("/project/dev_feature"), Blocker::Locked);
+        let mut picker = RemovePicker::new(make_worktrees(), true, facts, true);
+
+        let mut terminal = test_terminal(80, 20);
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("protected"), "expected hint in: {text}");
+        assert!(text.contains("locked"), "expected hint in: {text}");
+
+        feed_picker(&mut picker, &[key_char('a'), enter()]).unwrap();
+        let text: Vec<String> = picker
+            .removal_summary(&[1, 2, 3], true)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            text,
+            vec![
+                "  Worktrees:",
+                "    <detached> (dev_detached)  status unknown; changes may be lost",
+                "",
+                "  Skipped:",
+                "    main (dev_main)  protected (use --allow-protected)",
+                "    feature (dev_feature)  locked (run `grov unlock` first)",
+            ]
+        );
+        let choice = done(feed_picker(&mut picker, &[key_char('y')]).unwrap());
+        assert_eq!(choice.worktree_indices, vec![3]);
+    }
+
+    #[test]
+    fn blocker_checks_lock_then_protection() {
+        let config = GrovConfig::default();
+        let mut worktrees = make_worktrees();
+        assert_eq!(
+            blocker(&config, &worktrees[1], Some("main"), false),
+            Some(Blocker::Protected)
+        );
+        assert_eq!(blocker(&config, &worktrees[1], Some("main"), true), None);
+        worktrees[2].locked = Some(String::new());
+        assert_eq!(
+            blocker(&config, &worktrees[2], Some("main"), true),
+            Some(Blocker::Locked)
+        );
     }
 }
@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use crate::cli::InitShell;
+
+/// Environment variable pointing at the file the shell wrapper reads after
+/// grov exits. A child process cannot change its parent's directory, so
+/// commands write the target path here and the wrapper does the `cd`.
+pub const CD_FILE_ENV: &str = "GROV_CD_FILE";
+
+const POSIX_WRAPPER: &str = r#"grov() {
+    local grov_cd_file grov_status
+    grov_cd_file="$(mktemp "${TMPDIR:-/tmp}/grov-cd.XXXXXX")" || return
+    GROV_CD_FILE="$grov_cd_file" command grov "$@"
+    grov_status=$?
+    if [ -s "$grov_cd_file" ]; then
+        cd -- "$(cat "$grov_cd_file")" || grov_status=$?
+    fi
+    rm -f -- "$grov_cd_file"
+    return "$grov_status"
+}
+"#;
+
+const FISH_WRAPPER: &str = r#"function grov
+    set -l grov_cd_file (mktemp)
+    or return
+    env GROV_CD_FILE=$grov_cd_file grov $argv
+    set -l grov_status $status
+    if test -s $grov_cd_file
+        cd (cat $grov_cd_file)
+        or set grov_status $status
+    end
+    rm -f $grov_cd_file
+    return $grov_status
+end
+"#;
+
+pub fn execute(shell: InitShell) {
+    print!("{}", wrapper(shell));
+}
+
+fn wrapper(shell: InitShell) -> &'static str {
+    match shell {
+        InitShell::Bash | InitShell::Zsh => POSIX_WRAPPER,
+        InitShell::Fish => FISH_WRAPPER,
+    }
+}
+
+/// Ask the shell wrapper to change into `path` once grov exits.
+///
+/// Returns `Ok(false)` when grov is not running under the wrapper.
+pub(crate) fn request_cd(path: &Path) -> anyhow::Result<bool> {
+    let Some(cd_file) = std::env::var_os(CD_FILE_ENV).filter(|f| !f.is_empty()) else {
+        return Ok(false);
+    };
+    std::fs::write(cd_file, path.to_string_lossy().as_bytes())?;
+    Ok(true)
+}
@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use console::style;
+use serde::Serialize;
+
+use crate::cli::StatusFormat;
+use crate::commands::list::STATUS_TIMEOUT;
+use crate::config::read_config;
+use crate::git::log::{self, StashEntry};
+use crate::git::repo::{current_branch, default_branch, find_bare_repo};
+use crate::git::status::{self, ChangeCounts};
+use crate::git::worktree::{branch_exists_local, branch_exists_remote, list_worktrees};
+use crate::hooks::Hook;
+
+/// What `grov status` knows about the current worktree.
+///
+/// This is the schema of `--format json`; keep field names stable.
+#[derive(Debug, Serialize)]
+pub struct WorktreeReport {
+    pub project: String,
+    pub bare_repo: PathBuf,
+    pub worktree: PathBuf,
+    /// `None` when HEAD is detached.
+    pub branch: Option<String>,
+    pub head: String,
+    pub upstream: Option<String>,
+    /// An upstream is configured but no longer exists.
+    pub upstream_gone: bool,
+    pub ahead: Option<u32>,
+    pub behind: Option<u32>,
+    /// The default branch this worktree is compared against, e.g. `origin/main`.
+    pub base: Option<String>,
+    pub base_ahead: Option<u32>,
+    pub base_behind: Option<u32>,
+    pub dirty: bool,
+    pub changes: ChangeCounts,
+    /// Stashes made on this branch, newest first.
+    pub stashes: Vec<StashEntry>,
+    /// Lock reason (empty without one), `None` if not locked.
+    pub locked: Option<String>,
+    /// Configured hook commands by hook name.
+    pub hooks: BTreeMap<&'static str, Vec<String>>,
+}
+
+pub fn execute(format: StatusFormat) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let report = collect(&repo, &cwd)?;
+
+    match format {
+        StatusFormat::Human => print_human(&report),
+        StatusFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+    Ok(())
+}
+
+fn collect(repo: &Path, cwd: &Path) -> anyhow::Result<WorktreeReport> {
+    let cwd_canonical = std::fs::canonicalize(cwd)?;
+    // The innermost worktree containing cwd, in case worktrees are nested
+    let wt = list_worktrees(repo)?
+        .into_iter()
+        .filter(|wt| !wt.is_bare)
+        .filter_map(|wt| {
+            let root = std::fs::canonicalize(&wt.path).ok()?;
+            cwd_canonical
+                .starts_with(&root)
+                .then(|| (root.components().count(), wt))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, wt)| wt)
+        .ok_or_else(|| {
+            anyhow!("not inside a worktree; run `grov list` to see this project's worktrees")
+        })?;
+
+    let config = read_config(repo)?;
+    let branch = current_branch(cwd)?;
+    let summary = status::summary(&wt.path, config.status.untracked, STATUS_TIMEOUT)?;
+
+    let base = default_branch(repo).ok().and_then(|default| {
+        if branch_exists_remote(repo, &default) {
+            Some(format!("origin/{default}"))
+        } else {
+            branch_exists_local(repo, &default).then_some(default)
+        }
+    });
+    let base_ab = match &base {
+        Some(base) => status::ahead_behind(&wt.path, base)?,
+        None => None,
+    };
+
+    let stashes = match &branch {
+        Some(branch) => log::stash_list(repo)?
+            .into_iter()
+            .filter(|s| s.branch.as_ref() == Some(branch))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let hooks = Hook::ALL
+        .iter()
+        .map(|hook| (hook.name(), hook.commands(&config.hooks).to_vec()))
+        .collect();
+
+    let project = repo
+        .parent()
+        .and_then(Path::file_name)
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    Ok(WorktreeReport {
+        project,
+        bare_repo: repo.to_path_buf(),
+        worktree: wt.path,
+        branch,
+        head: wt.head,
+        upstream_gone: summary.upstream.is_some() && summary.ahead_behind.is_none(),
+        upstream: summary.upstream,
+        ahead: summary.ahead_behind.map(|(ahead, _)| ahead),
+        behind: summary.ahead_behind.map(|(_, behind)| behind),
+        base,
+        base_ahead: base_ab.map(|(ahead, _)| ahead),
+        base_behind: base_ab.map(|(_, behind)| behind),
+        dirty: summary.dirty,
+        changes: summary.changes,
+        stashes,
+        locked: wt.locked,
+        hooks,
+    })
+}
+
+fn print_human(report: &WorktreeReport) {
+    let label = |name: &str| style(format!("  {name:<10}")).dim();
+    let none = || style("none").dim().to_string();
+
+    println!(
+        "{}  {}",
+        style(&report.project).bold(),
+        style(report.bare_repo.display()).dim()
+    );
+    println!();
+
+    println!("{}{}", label("Worktree"), report.worktree.display());
+
+    let branch = match &report.branch {
+        Some(branch) => style(branch).cyan().bold().to_string(),
+        None => format!(
+            "{} {}",
+            style("(detached)").yellow(),
+            style(short_sha(&report.head)).dim()
+        ),
+    };
+    println!("{}{branch}", label("Branch"));
+
+    let upstream = match &report.upstream {
+        Some(upstream) if report.upstream_gone => {
+            format!("{upstream}  {}", style("[gone]").red())
+        }
+        Some(upstream) => format!(
+            "{upstream}  {}",
+            format_counts(report.ahead.zip(report.behind))
+        ),
+        None => none(),
+    };
+    println!("{}{upstream}", label("Upstream"));
+
+    let base = match &report.base {
+        Some(base) => format!(
+            "{base}  {}",
+            format_counts(report.base_ahead.zip(report.base_behind))
+        ),
+        None => none(),
+    };
+    println!("{}{base}", label("Base"));
+
+    println!("{}{}", label("Changes"), format_changes(report.changes));
+
+    if report.stashes.is_empty() {
+        println!("{}{}", label("Stashes"), none());
+    }
+    for (i, stash) in report.stashes.iter().enumerate() {
+        let name = if i == 0 { "Stashes" } else { "" };
+        println!(
+            "{}{} {}",
+            label(name),
+            style(format!("{}:", stash.name)).yellow(),
+            stash.message
+        );
+    }
+
+    let lock = match report.locked.as_deref() {
+        Some("") => style("locked").magenta().to_string(),
+        Some(reason) => format!(
+            "{} {}",
+            style("locked").magenta(),
+            style(format!("({reason})")).dim()
+        ),
+        None => style("unlocked").dim().to_string(),
+    };
+    println!("{}{lock}", label("Lock"));
+
+    let hooks: Vec<String> = report
+        .hooks
+        .iter()
+        .filter(|(_, commands)| !commands.is_empty())
+        .map(|(name, commands)| format!("{name} ({})", commands.len()))
+        .collect();
+    let hooks = if hooks.is_empty() {
+        none()
+    } else {
+        hooks.join(", ")
+    };
+    println!("{}{hooks}", label("Hooks"));
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+fn format_counts(ab: Option<(u32, u32)>) -> String {
+    match ab {
+        Some((0, 0)) => style("up to date").dim().to_string(),
+        Some((ahead, behind)) => {
+            let mut parts = Vec::new();
+            if ahead > 0 {
+                parts.push(style(format!("↑{ahead}")).green().to_string());
+            }
+            if behind > 0 {
+                parts.push(style(format!("↓{behind}")).red().to_string());
+            }
+            parts.join(" ")
+        }
+        None => String::new(),
+    }
+}
+
+fn format_changes(changes: ChangeCounts) -> String {
+    let parts: Vec<String> = [
+        (changes.conflicted, "conflicted"),
+        (changes.staged, "staged"),
+        (changes.modified, "modified"),
+        (changes.untracked, "untracked"),
+    ]
+    .into_iter()
+    .filter(|(n, _)| *n > 0)
+    .map(|(n, kind)| format!("{n} {kind}"))
+    .collect();
+    if parts.is_empty() {
+        style("✓ clean").green().to_string()
+    } else {
+        style(parts.join(", ")).yellow().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_changes() {
+        let changes = ChangeCounts {
+            staged: 2,
+            modified: 0,
+            untracked: 3,
+            conflicted: 1,
+        };
+        assert_eq!(
+            console::strip_ansi_codes(&format_changes(changes)),
+            "1 conflicted, 2 staged, 3 untracked"
+        );
+        assert_eq!(
+            console::strip_ansi_codes(&format_changes(ChangeCounts::default())),
+            "✓ clean"
+        );
+    }
+
+    #[test]
+    fn formats_counts() {
+        assert_eq!(
+            console::strip_ansi_codes(&format_counts(Some((2, 1)))),
+            "↑2 ↓1"
+        );
+        assert_eq!(
+            console::strip_ansi_codes(&format_counts(Some((0, 0)))),
+            "up to date"
+        );
+        assert_eq!(format_counts(None), "");
+    }
+}
@@ -0,0 +1,35 @@
+use console::style;
+
+use crate::cli::MatchMode;
+use crate::commands::remove::resolve_by_name;
+use crate::commands::shell_init::request_cd;
+use crate::git::repo::find_bare_repo;
+use crate::git::worktree::list_worktrees;
+
+pub fn execute(name: &str, match_mode: MatchMode) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let worktrees = list_worktrees(&repo)?;
+
+    let wt = &worktrees[resolve_by_name(&worktrees, name, match_mode)?];
+    if wt.is_bare {
+        anyhow::bail!("cannot switch to the bare repository entry");
+    }
+    if !wt.path.exists() {
+        anyhow::bail!("worktree directory is missing: {}", wt.path.display());
+    }
+
+    // Without the shell wrapper, print the path so `cd "$(grov switch x)"` works
+    if !request_cd(&wt.path)? {
+        println!("{}", wt.path.display());
+        return Ok(());
+    }
+
+    eprintln!(
+        "{} Switched to {}",
+        style("\u{2713}").green().bold(),
+        style(wt.branch.as_deref().unwrap_or(name)).cyan().bold(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,979 @@
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Command as Process;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+use console::style;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::branch::BranchPolicy;
+use crate::commands::add::{self, AddPicker, BranchChoice};
+use crate::commands::list::{self, ListEntry, WorktreeStatus};
+use crate::commands::shell_init::{CD_FILE_ENV, request_cd};
+use crate::config::{UntrackedFiles, read_config};
+use crate::git::executor::run_git_ok;
+use crate::git::log::{self, LogLine};
+use crate::git::repo::find_bare_repo;
+use crate::git::status;
+use crate::git::worktree::{available_branches, list_worktrees, lock_worktree, unlock_worktree};
+use crate::tui::confirm::{self, Confirm};
+use crate::tui::loader::Loader;
+use crate::tui::terminal::{Tui, run_tui, suspend};
+use crate::tui::text_input::{self, TextInput};
+use crate::tui::{FlowOutcome, clipboard, theme};
+
+/// How often worktree status is re-read in the background.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Commits shown in the preview pane.
+const PREVIEW_COMMITS: usize = 10;
+
+/// Longest the preview's `git status` may take.
+const PREVIEW_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub fn execute() -> anyhow::Result<()> {
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        anyhow::bail!("grov ui requires an interactive terminal");
+    }
+
+    let cwd = std::env::current_dir()?;
+    let repo = find_bare_repo(&cwd)?;
+    let config = read_config(&repo)?;
+    let project = repo
+        .parent()
+        .and_then(Path::file_name)
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let entries = list::worktree_entries(&repo, &cwd, config.status.untracked)?;
+    let policy = BranchPolicy::from_config(&config.branch)?;
+
+    let session = Session {
+        repo,
+        cwd,
+        untracked: config.status.untracked,
+        policy,
+    };
+    let switch_to = run_tui(|terminal| session.run(terminal, Dashboard::new(project, entries)))?;
+
+    if let Some(path) = switch_to {
+        // Without the shell wrapper, print the path so `cd "$(grov ui)"` works
+        if !request_cd(&path)? {
+            println!("{}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Something the dashboard asks its event loop to do.
+#[derive(Debug, PartialEq)]
+enum Command {
+    Quit,
+    /// Leave the dashboard and change into this worktree.
+    Switch(PathBuf),
+    Refresh,
+    Fetch,
+    /// Open the branch picker.
+    StartAdd,
+    Add(BranchChoice),
+    /// Remove the worktree in this directory (matched by directory name).
+    Remove(String),
+    Lock(PathBuf, Option<String>),
+    Unlock(PathBuf),
+    Shell(PathBuf),
+    CopyPath(PathBuf),
+}
+
+/// Results of work done off the UI thread.
+enum Update {
+    Entries(Result<Vec<ListEntry>, String>),
+    Fetched(Result<(), String>),
+}
+
+/// Recent commits and changed files of one worktree.
+#[derive(Debug, Default)]
+struct Preview {
+    /// The worktree's HEAD when the preview was loaded.
+    head: String,
+    commits: Vec<LogLine>,
+    changes: Vec<String>,
+    error: Option<String>,
+    /// Shown until a reload replaces it, e.g. after a status refresh.
+    stale: bool,
+}
+
+enum Mode {
+    Browse,
+    Add(Box<AddPicker>),
+    ConfirmRemove { dir_name: String, confirm: Confirm },
+    LockReason { path: PathBuf, input: TextInput },
+}
+
+struct Message {
+    text: String,
+    is_error: bool,
+}
+
+struct Dashboard {
+    project: String,
+    /// Non-bare worktrees, in `git worktree list` order.
+    entries: Vec<ListEntry>,
+    list_state: ListState,
+    previews: HashMap<PathBuf, Preview>,
+    mode: Mode,
+    message: Option<Message>,
+    refreshing: bool,
+    fetching: bool,
+}
+
+impl Dashboard {
+    fn new(project: String, entries: Vec<ListEntry>) -> Self {
+        let mut dashboard = Self {
+            project,
+            entries: Vec::new(),
+            list_state: ListState::default(),
+            previews: HashMap::new(),
+            mode: Mode::Browse,
+            message: None,
+            refreshing: false,
+            fetching: false,
+        };
+        dashboard.set_entries(entries);
+        // Start on the worktree grov ui was started from
+        if let Some(i) = dashboard.entries.iter().position(|e| e.is_current) {
+            dashboard.list_state.select(Some(i));
+        }
+        dashboard
+    }
+
+    /// Replace the worktree list, keeping the selection on the same worktree.
+    /// Cached previews are kept, marked stale, while their HEAD is unchanged.
+    fn set_entries(&mut self, entries: Vec<ListEntry>) {
+        let selected_path = self.selected().map(|e| e.worktree.path.clone());
+        self.entries = entries
+            .into_iter()
+            .filter(|e| !e.worktree.is_bare)
+            .collect();
+        let index = selected_path
+            .and_then(|path| self.entries.iter().position(|e| e.worktree.path == path))
+            .or_else(|| {
+                let previous = self.list_state.selected().unwrap_or(0);
+                (!self.entries.is_empty()).then(|| previous.min(self.entries.len() - 1))
+            });
+        self.list_state.select(index);
+        let entries = &self.entries;
+        self.previews.retain(|path, preview| {
+            preview.stale = true;
+            entries
+                .iter()
+                .any(|e| e.worktree.path == *path && e.worktree.head == preview.head)
+        });
+    }
+
+    fn selected(&self) -> Option<&ListEntry> {
+        self.list_state.selected().and_then(|i| self.entries.get(i))
+    }
+
+    /// The selected worktree's directory, if it still exists.
+    fn selected_dir(&mut self) -> Option<PathBuf> {
+        let entry = self.selected()?;
+        if entry.status == Some(WorktreeStatus::Missing) {
+            let message = format!("{} is missing", entry.worktree.path.display());
+            self.set_message(message, true);
+            return None;
+        }
+        Some(entry.worktree.path.clone())
+    }
+
+    /// Path and HEAD of the selected worktree, if its preview is missing or
+    /// out of date.
+    fn wanted_preview(&self) -> Option<(PathBuf, String)> {
+        let entry = self.selected()?;
+        let path = &entry.worktree.path;
+        let outdated = self
+            .previews
+            .get(path)
+            .is_none_or(|p| p.stale || p.head != entry.worktree.head);
+        (entry.status != Some(WorktreeStatus::Missing) && outdated)
+            .then(|| (path.clone(), entry.worktree.head.clone()))
+    }
+
+    fn set_message(&mut self, text: impl Into<String>, is_error: bool) {
+        self.message = Some(Message {
+            text: text.into(),
+            is_error,
+        });
+    }
+
+    fn start_add(&mut self, picker: AddPicker) {
+        self.mode = Mode::Add(Box::new(picker));
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let current = self.list_state.selected().unwrap_or(0);
+        let next = if down {
+            (current + 1).min(self.entries.len() - 1)
+        } else {
+            current.saturating_sub(1)
+        };
+        self.list_state.select(Some(next));
+    }
+
+    fn handle_event(&mut self, event: &Event) -> Option<Command> {
+        match &mut self.mode {
+            Mode::Browse => self.handle_browse(event),
+            Mode::Add(picker) => match picker.handle_event(event) {
+                Ok(FlowOutcome::Continue) => None,
+                Ok(FlowOutcome::Done(choice)) => {
+                    self.mode = Mode::Browse;
+                    Some(Command::Add(choice))
+                }
+                // The picker's only error is being cancelled
+                Err(_) => {
+                    self.mode = Mode::Browse;
+                    None
+                }
+            },
+            Mode::ConfirmRemove { dir_name, confirm } => match confirm.handle_event(event) {
+                confirm::Action::Confirmed(true) => {
+                    let dir_name = std::mem::take(dir_name);
+                    self.mode = Mode::Browse;
+                    Some(Command::Remove(dir_name))
+                }
+                confirm::Action::Confirmed(false) | confirm::Action::Cancel => {
+                    self.mode = Mode::Browse;
+                    None
+                }
+                confirm::Action::Continue => None,
+            },
+            Mode::LockReason { path, input } => match input.handle_event(event) {
+                text_input::Action::Submit(reason) => {
+                    let path = std::mem::take(path);
+                    self.mode = Mode::Browse;
+                    Some(Command::Lock(path, Some(reason).filter(|r| !r.is_empty())))
+                }
+                text_input::Action::Cancel => {
+                    self.mode = Mode::Browse;
+                    None
+                }
+                text_input::Action::Continue => None,
+            },
+        }
+    }
+
+    fn handle_browse(&mut self, event: &Event) -> Option<Command> {
+        let Event::Key(KeyEvent {
+            code, modifiers, ..
+        }) = event
+        else {
+            return None;
+        };
+        if *modifiers == KeyModifiers::CONTROL && *code == KeyCode::Char('c') {
+            return Some(Command::Quit);
+        }
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.move_selection(false);
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.move_selection(true);
+                None
+            }
+            KeyCode::Char('r') => Some(Command::Refresh),
+            KeyCode::Char('f') => Some(Command::Fetch),
+            KeyCode::Char('a') => Some(Command::StartAdd),
+            KeyCode::Enter => self.selected_dir().map(Command::Switch),
+            KeyCode::Char('s') => self.selected_dir().map(Command::Shell),
+            KeyCode::Char('y') => self
+                .selected()
+                .map(|e| Command::CopyPath(e.worktree.path.clone())),
+            KeyCode::Char('d') => {
+                let entry = self.selected()?;
+                let label = format!("Remove worktree {}?", display_name(entry));
+                self.mode = Mode::ConfirmRemove {
+                    dir_name: entry.dir_name.clone(),
+                    confirm: Confirm::new(label),
+                };
+                None
+            }
+            KeyCode::Char('l') => {
+                let entry = self.selected()?;
+                let path = entry.worktree.path.clone();
+                if entry.worktree.locked.is_some() {
+                    return Some(Command::Unlock(path));
+                }
+                let input =
+                    TextInput::new(format!("Lock {} (reason, optional)", display_name(entry)));
+                self.mode = Mode::LockReason { path, input };
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn render(&self, frame: &mut Frame) {
+        if let Mode::Add(picker) = &self.mode {
+            picker.render(frame);
+            return;
+        }
+
+        let [header, body, message, help] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let mut title = vec![
+            Span::styled("  grov", theme::CYAN),
+            Span::styled("  ·  ", theme::DIM),
+            Span::raw(self.project.as_str()),
+        ];
+        if self.fetching {
+            title.push(Span::styled("  fetching…", theme::DIM));
+        } else if self.refreshing {
+            title.push(Span::styled("  refreshing…", theme::DIM));
+        }
+        frame.render_widget(Paragraph::new(Line::from(title)), header);
+
+        let [list_area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+                .areas(body);
+        self.render_list(frame, list_area);
+        self.render_preview(frame, preview_area);
+
+        if let Some(msg) = &self.message {
+            let style = if msg.is_error {
+                theme::ERROR
+            } else {
+                theme::DIM
+            };
+            frame.render_widget(
+                Paragraph::new(Span::styled(format!("  {}", msg.text), style)),
+                message,
+            );
+        }
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!("  {}", theme::HELP_DASHBOARD),
+                theme::DIM,
+            )),
+            help,
+        );
+
+        match &self.mode {
+            Mode::ConfirmRemove { confirm, .. } => {
+                let area = popup(body, 4);
+                frame.render_widget(Clear, area);
+                confirm.render(frame, area);
+            }
+            Mode::LockReason { input, .. } => {
+                let area = popup(body, 4);
+                frame.render_widget(Clear, area);
+                input.render(frame, area);
+            }
+            Mode::Browse | Mode::Add(_) => {}
+        }
+    }
+
+    fn render_list(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered()
+            .title(" Worktrees ")
+            .border_style(theme::DIM);
+        if self.entries.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Span::styled(" No worktrees found.", theme::DIM)).block(block),
+                area,
+            );
+            return;
+        }
+
+        let name_width = self
+            .entries
+            .iter()
+            .map(|e| display_name(e).chars().count())
+            .max()
+            .unwrap_or(0);
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let name = display_name(entry);
+                let pad = " ".repeat(name_width.saturating_sub(name.chars().count()));
+                let (marker, name_style) = if entry.is_current {
+                    ("● ", theme::CYAN)
+                } else {
+                    ("○ ", theme::DIM)
+                };
+                let status = entry.status.unwrap_or(WorktreeStatus::Unknown);
+                let mut spans = vec![
+                    Span::styled(marker, name_style),
+                    Span::styled(format!("{name}{pad}  "), name_style),
+                    Span::styled(status.label(), status_style(status)),
+                ];
+                if let Some(ahead) = entry.ahead.filter(|&n| n > 0) {
+                    spans.push(Span::styled(format!(" ↑{ahead}"), theme::GREEN));
+                }
+                if let Some(behind) = entry.behind.filter(|&n| n > 0) {
+                    spans.push(Span::styled(
+                        format!(" ↓{behind}"),
+                        Style::new().fg(Color::Red),
+                    ));
+                }
+                if entry.worktree.locked.is_some() {
+                    spans.push(Span::styled(" locked", Style::new().fg(Color::Magenta)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_symbol("❯ ")
+            .highlight_style(theme::HIGHLIGHT);
+        let mut state = self.list_state;
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn render_preview(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered()
+            .title(" Preview ")
+            .border_style(theme::DIM);
+        let Some(entry) = self.selected() else {
+            frame.render_widget(block, area);
+            return;
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                entry.worktree.path.display().to_string(),
+                theme::DIM,
+            )),
+            upstream_line(entry),
+        ];
+        if let Some(reason) = &entry.worktree.locked {
+            let reason = if reason.is_empty() {
+                String::new()
+            } else {
+                format!(" ({reason})")
+            };
+            lines.push(Line::from(Span::styled(
+                format!("locked{reason}"),
+                Style::new().fg(Color::Magenta),
+            )));
+        }
+        lines.push(Line::default());
+
+        match self.previews.get(&entry.worktree.path) {
+            _ if entry.status == Some(WorktreeStatus::Missing) => lines.push(Line::from(
+                Span::styled("Directory is missing", theme::ERROR),
+            )),
+            None => lines.push(Line::from(Span::styled("Loading…", theme::DIM))),
+            Some(preview) => {
+                if let Some(error) = &preview.error {
+                    lines.push(Line::from(Span::styled(error.as_str(), theme::ERROR)));
+                }
+                lines.push(Line::from(Span::styled("Recent commits", theme::CYAN)));
+                let now = log::now();
+                for commit in &preview.commits {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{} ", commit.short_sha), theme::DIM),
+                        Span::raw(commit.subject.as_str()),
+                        Span::styled(
+                            format!("  {}", log::relative_age(commit.time, now)),
+                            theme::DIM,
+                        ),
+                    ]));
+                }
+                lines.push(Line::default());
+                if preview.changes.is_empty() {
+                    lines.push(Line::from(Span::styled("No changes", theme::DIM)));
+                } else {
+                    lines.push(Line::from(Span::styled(
+                        format!("Changes ({})", preview.changes.len()),
+                        theme::CYAN,
+                    )));
+                    for change in &preview.changes {
+                        let (xy, path) = change.split_at(change.len().min(2));
+                        lines.push(Line::from(vec![
+                            Span::styled(xy.to_string(), Style::new().fg(Color::Yellow)),
+                            Span::raw(path.to_string()),
+                        ]));
+                    }
+                }
+            }
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+fn display_name(entry: &ListEntry) -> &str {
+    entry.worktree.branch.as_deref().unwrap_or("(detached)")
+}
+
+fn status_style(status: WorktreeStatus) -> Style {
+    match status {
+        WorktreeStatus::Clean => Style::new().fg(Color::Green),
+        WorktreeStatus::Dirty | WorktreeStatus::Unknown => Style::new().fg(Color::Yellow),
+        WorktreeStatus::Missing => Style::new().fg(Color::Red),
+    }
+}
+
+fn upstream_line(entry: &ListEntry) -> Line<'static> {
+    match (&entry.upstream, entry.ahead.zip(entry.behind)) {
+        (None, _) => Line::from(Span::styled("no upstream", theme::DIM)),
+        (Some(upstream), None) => Line::from(vec![
+            Span::raw(format!("{upstream} ")),
+            Span::styled("[gone]", theme::ERROR),
+        ]),
+        (Some(upstream), Some((ahead, behind))) => Line::from(vec![
+            Span::raw(format!("{upstream} ")),
+            Span::styled(format!("↑{ahead} ↓{behind}"), theme::DIM),
+        ]),
+    }
+}
+
+/// A box `height` rows tall across the middle of `area`.
+fn popup(area: Rect, height: u16) -> Rect {
+    let height = height.min(area.height);
+    Rect::new(
+        area.x,
+        area.y + (area.height - height) / 2,
+        area.width,
+        height,
+    )
+}
+
+/// What the event loop needs besides the dashboard itself.
+struct Session {
+    repo: PathBuf,
+    cwd: PathBuf,
+    untracked: UntrackedFiles,
+    policy: BranchPolicy,
+}
+
+impl Session {
+    /// Run the dashboard until the user quits, returning the worktree to
+    /// switch to, if any.
+    fn run(&self, terminal: &mut Tui, mut dash: Dashboard) -> anyhow::Result<Option<PathBuf>> {
+        let (tx, rx) = mpsc::channel();
+        let mut last_refresh = Instant::now();
+        // One worker that only loads the latest selection
+        let untracked = self.untracked;
+        let mut previews = Loader::new(move |(path, head): &(PathBuf, String)| {
+            load_preview(path, head.clone(), untracked)
+        });
+
+        loop {
+            for ((path, _), preview) in previews.finished() {
+                dash.previews.insert(path, preview);
+            }
+            if let Some(wanted) = dash.wanted_preview() {
+                previews.request(wanted);
+            }
+            if let Mode::Add(picker) = &mut dash.mode {
+                picker.update_preview(&self.repo);
+            }
+            terminal.draw(|frame| dash.render(frame))?;
+
+            self.apply_updates(&mut dash, &rx, &tx);
+            if !dash.refreshing && last_refresh.elapsed() >= REFRESH_INTERVAL {
+                self.refresh(&mut dash, &tx);
+                last_refresh = Instant::now();
+            }
+
+            if !event::poll(Duration::from_millis(100))? {
+                continue;
+            }
+            let Some(command) = dash.handle_event(&event::read()?) else {
+                continue;
+            };
+            match command {
+                Command::Quit => return Ok(None),
+                Command::Switch(path) => return Ok(Some(path)),
+                Command::Refresh => {
+                    self.refresh(&mut dash, &tx);
+                    last_refresh = Instant::now();
+                }
+                Command::Fetch if dash.fetching => {}
+                Command::Fetch => {
+                    dash.fetching = true;
+                    let repo = self.repo.clone();
+                    let tx = tx.clone();
+                    std::thread::spawn(move || {
+                        let result = run_git_ok(Some(&repo), &["fetch", "--prune", "origin"])
+                            .map(drop)
+                            .map_err(|err| format!("{err:#}"));
+                        let _ = tx.send(Update::Fetched(result));
+                    });
+                }
+                Command::StartAdd => match self.add_picker(&dash) {
+                    Ok(picker) => dash.start_add(picker),
+                    Err(err) => dash.set_message(format!("{err:#}"), true),
+                },
+                Command::Add(choice) => {
+                    let (name, args) = match &choice {
+                        BranchChoice::Existing(name) => (name, vec!["add", name]),
+                        BranchChoice::New { name, base } => {
+                            (name, vec!["add", name, "--base", base])
+                        }
+                    };
+                    let ok = self.run_grov(terminal, &args)?;
+                    self.report(&mut dash, ok, format!("Added {name}"), "grov add failed");
+                    self.refresh(&mut dash, &tx);
+                }
+                Command::Remove(dir_name) => {
+                    let ok = self.run_grov(terminal, &["remove", &dir_name, "--match", "dir"])?;
+                    self.report(
+                        &mut dash,
+                        ok,
+                        format!("Removed {dir_name}"),
+                        "grov remove failed",
+                    );
+                    self.refresh(&mut dash, &tx);
+                }
+                Command::Lock(path, reason) => {
+                    match lock_worktree(&self.repo, &path, reason.as_deref()) {
+                        Ok(()) => dash.set_message(format!("Locked {}", path.display()), false),
+                        Err(err) => dash.set_message(format!("{err:#}"), true),
+                    }
+                    self.refresh(&mut dash, &tx);
+                }
+                Command::Unlock(path) => {
+                    match unlock_worktree(&self.repo, &path) {
+                        Ok(()) => dash.set_message(format!("Unlocked {}", path.display()), false),
+                        Err(err) => dash.set_message(format!("{err:#}"), true),
+                    }
+                    self.refresh(&mut dash, &tx);
+                }
+                Command::Shell(path) => {
+                    let shell = std::env::var_os("SHELL").unwrap_or_else(|| "sh".into());
+                    let status = suspend(terminal, || {
+                        eprintln!(
+                            "{} exit the shell to return to grov ui",
+                            style("note:").cyan().bold()
+                        );
+                        Process::new(&shell).current_dir(&path).status()
+                    })?;
+                    if let Err(err) = status {
+                        dash.set_message(format!("could not start shell: {err}"), true);
+                    }
+                    self.refresh(&mut dash, &tx);
+                }
+                Command::CopyPath(path) => match clipboard::copy(&path.to_string_lossy()) {
+                    Ok(()) => dash.set_message(format!("Copied {}", path.display()), false),
+                    Err(err) => dash.set_message(format!("could not copy path: {err}"), true),
+                },
+            }
+        }
+    }
+
+    fn apply_updates(&self, dash: &mut Dashboard, rx: &Receiver<Update>, tx: &Sender<Update>) {
+        while let Ok(update) = rx.try_recv() {
+            match update {
+                Update::Entries(result) => {
+                    dash.refreshing = false;
+                    match result {
+                        Ok(entries) => dash.set_entries(entries),
+                        Err(err) => dash.set_message(err, true),
+                    }
+                }
+                Update::Fetched(result) => {
+                    dash.fetching = false;
+                    match result {
+                        Ok(()) => dash.set_message("Fetched origin", false),
+                        Err(err) => dash.set_message(format!("fetch failed: {err}"), true),
+                    }
+                    self.refresh(dash, tx);
+                }
+            }
+        }
+    }
+
+    /// Re-read worktree status on a background thread.
+    fn refresh(&self, dash: &mut Dashboard, tx: &Sender<Update>) {
+        dash.refreshing = true;
+        let (repo, cwd, untracked) = (self.repo.clone(), self.cwd.clone(), self.untracked);
+        let tx = tx.clone();
+        std::thread::spawn(move || {
+            let entries = list::worktree_entries(&repo, &cwd, untracked)
+                .map_err(|err| format!("could not read worktrees: {err:#}"));
+            let _ = tx.send(Update::Entries(entries));
+        });
+    }
+
+    fn add_picker(&self, dash: &Dashboard) -> anyhow::Result<AddPicker> {
+        let worktrees = list_worktrees(&self.repo)?;
+        let branches = available_branches(&self.repo, &worktrees)?;
+        let base = dash.selected().and_then(|e| e.worktree.branch.clone());
+        let mut picker = AddPicker::new(branches, base).with_policy(self.policy.clone());
+        if let Some(base) = add::preview_base(&self.repo) {
+            picker = picker.with_preview(base);
+        }
+        Ok(picker)
+    }
+
+    /// Run grov itself on the plain terminal so its output and prompts show,
+    /// then wait for Enter. Returns whether it succeeded.
+    ///
+    /// The child runs without the shell wrapper's cd file, so adding a
+    /// worktree does not move the shell once the dashboard exits.
+    fn run_grov(&self, terminal: &mut Tui, args: &[&str]) -> anyhow::Result<bool> {
+        let exe = std::env::current_exe()?;
+        let project_dir = self.repo.parent().unwrap_or(&self.repo);
+        let status = suspend(terminal, || -> std::io::Result<_> {
+            let status = Process::new(&exe)
+                .args(args)
+                .env_remove(CD_FILE_ENV)
+                .current_dir(project_dir)
+                .status()?;
+            eprint!("\n{}", style("Press Enter to return to grov ui").dim());
+            std::io::stdin().read_line(&mut String::new())?;
+            Ok(status)
+        })??;
+        Ok(status.success())
+    }
+
+    fn report(&self, dash: &mut Dashboard, ok: bool, success: String, failure: &str) {
+        if ok {
+            dash.set_message(success, false);
+        } else {
+            dash.set_message(failure, true);
+        }
+    }
+}
+
+fn load_preview(path: &Path, head: String, untracked: UntrackedFiles) -> Preview {
+    let mut preview = Preview {
+        head,
+        ..Preview::default()
+    };
+    match log::recent_commits(path, PREVIEW_COMMITS) {
+        Ok(commits) => preview.commits = commits,
+        Err(err) => preview.error = Some(format!("{err:#}")),
+    }
+    match status::short_status(path, untracked, PREVIEW_TIMEOUT) {
+        Ok(changes) => preview.changes = changes,
+        Err(err) => preview.error = Some(format!("{err:#}")),
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::worktree::{BranchInfo, WorktreeInfo};
+    use crate::tui::test_helpers::{buffer_text, enter, esc, key_char, test_terminal, type_string};
+
+    fn entry(branch: &str, status: WorktreeStatus) -> ListEntry {
+        ListEntry {
+            worktree: WorktreeInfo {
+                path: PathBuf::from(format!("/project/test_{branch}")),
+                head: "abc1234".to_string(),
+                branch: Some(branch.to_string()),
+                is_bare: false,
+                locked: None,
+                prunable: None,
+            },
+            dir_name: format!("test_{branch}"),
+            is_detached: false,
+            is_current: false,
+            status: Some(status),
+            ahead: Some(1),
+            behind: Some(0),
+            upstream: Some(format!("origin/{branch}")),
+        }
+    }
+
+    fn dashboard() -> Dashboard {
+        let mut current = entry("main", WorktreeStatus::Clean);
+        current.is_current = true;
+        Dashboard::new(
+            "project".into(),
+            vec![
+                entry("feature", WorktreeStatus::Dirty),
+                current,
+                entry("gone", WorktreeStatus::Missing),
+            ],
+        )
+    }
+
+    #[test]
+    fn starts_on_current_worktree_and_moves() {
+        let mut dash = dashboard();
+        assert_eq!(display_name(dash.selected().unwrap()), "main");
+        dash.handle_event(&key_char('j'));
+        assert_eq!(display_name(dash.selected().unwrap()), "gone");
+        // No wrap-around at the end
+        dash.handle_event(&key_char('j'));
+        assert_eq!(display_name(dash.selected().unwrap()), "gone");
+        dash.handle_event(&key_char('k'));
+        dash.handle_event(&key_char('k'));
+        assert_eq!(display_name(dash.selected().unwrap()), "feature");
+    }
+
+    #[test]
+    fn refresh_keeps_selection_on_same_worktree() {
+        let mut dash = dashboard();
+        dash.set_entries(vec![
+            entry("new", WorktreeStatus::Clean),
+            entry("feature", WorktreeStatus::Clean),
+            entry("main", WorktreeStatus::Dirty),
+        ]);
+        assert_eq!(display_name(dash.selected().unwrap()), "main");
+        assert_eq!(dash.selected().unwrap().status, Some(WorktreeStatus::Dirty));
+    }
+
+    #[test]
+    fn remove_asks_for_confirmation() {
+        let mut dash = dashboard();
+        assert_eq!(dash.handle_event(&key_char('d')), None);
+        assert!(matches!(dash.mode, Mode::ConfirmRemove { .. }));
+        assert_eq!(
+            dash.handle_event(&key_char('y')),
+            Some(Command::Remove("test_main".into()))
+        );
+        assert!(matches!(dash.mode, Mode::Browse));
+
+        dash.handle_event(&key_char('d'));
+        assert_eq!(dash.handle_event(&esc()), None);
+        assert!(matches!(dash.mode, Mode::Browse));
+    }
+
+    #[test]
+    fn lock_prompts_for_reason_and_unlock_does_not() {
+        let mut dash = dashboard();
+        dash.handle_event(&key_char('l'));
+        for event in type_string("usb drive") {
+            assert_eq!(dash.handle_event(&event), None);
+        }
+        assert_eq!(
+            dash.handle_event(&enter()),
+            Some(Command::Lock(
+                PathBuf::from("/project/test_main"),
+                Some("usb drive".into())
+            ))
+        );
+
+        let mut locked = entry("main", WorktreeStatus::Clean);
+        locked.worktree.locked = Some(String::new());
+        let mut dash = Dashboard::new("project".into(), vec![locked]);
+        assert_eq!(
+            dash.handle_event(&key_char('l')),
+            Some(Command::Unlock(PathBuf::from("/project/test_main")))
+        );
+    }
+
+    #[test]
+    fn shell_and_switch_refuse_missing_worktrees() {
+        let mut dash = dashboard();
+        dash.handle_event(&key_char('j'));
+        assert_eq!(dash.handle_event(&key_char('s')), None);
+        assert_eq!(dash.handle_event(&enter()), None);
+        assert!(dash.message.as_ref().is_some_and(|m| m.is_error));
+        assert_eq!(dash.wanted_preview(), None);
+
+        dash.handle_event(&key_char('k'));
+        assert_eq!(
+            dash.handle_event(&enter()),
+            Some(Command::Switch(PathBuf::from("/project/test_main")))
+        );
+    }
+
+    #[test]
+    fn refresh_keeps_previews_whose_head_is_unchanged() {
+        let mut dash = dashboard();
+        let main = PathBuf::from("/project/test_main");
+        let feature = PathBuf::from("/project/test_feature");
+        for path in [&main, &feature] {
+            let preview = Preview {
+                head: "abc1234".into(),
+                ..Preview::default()
+            };
+            dash.previews.insert(path.clone(), preview);
+        }
+        assert_eq!(dash.wanted_preview(), None);
+
+        let mut moved = entry("feature", WorktreeStatus::Dirty);
+        moved.worktree.head = "def5678".into();
+        dash.set_entries(vec![moved, entry("main", WorktreeStatus::Clean)]);
+
+        // Kept for display, but reloaded in the background
+        assert!(dash.previews[&main].stale);
+        assert!(!dash.previews.contains_key(&feature));
+        assert_eq!(
+            dash.wanted_preview(),
+            Some((main.clone(), "abc1234".to_string()))
+        );
+    }
+
+    fn topic() -> Vec<BranchInfo> {
+        vec![BranchInfo {
+            name: "topic".into(),
+            local: true,
+            remote: false,
+            time: 0,
+            author: "Test".into(),
+            upstream: None,
+        }]
+    }
+
+    #[test]
+    fn add_picker_returns_choice() {
+        let mut dash = dashboard();
+        dash.start_add(AddPicker::new(topic(), Some("main".into())));
+        assert_eq!(
+            dash.handle_event(&enter()),
+            Some(Command::Add(BranchChoice::Existing("topic".into())))
+        );
+        assert!(matches!(dash.mode, Mode::Browse));
+
+        dash.start_add(AddPicker::new(topic(), None));
+        assert_eq!(dash.handle_event(&esc()), None);
+        assert!(matches!(dash.mode, Mode::Browse));
+    }
+
+    #[test]
+    fn renders_list_and_preview() {
+        let mut dash = dashboard();
+        dash.previews.insert(
+            PathBuf::from("/project/test_main"),
+            Preview {
+                commits: vec![LogLine {
+                    short_sha: "abc1234".into(),
+                    subject: "Add search box".into(),
+                    time: 0,
+                }],
+                changes: vec![" M src/lib.rs".into()],
+                ..Preview::default()
+            },
+        );
+        let mut terminal = test_terminal(100, 20);
+        terminal.draw(|frame| dash.render(frame)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("feature"), "expected worktree in: {text}");
+        assert!(text.contains("✦ dirty"), "expected status in: {text}");
+        assert!(
+            text.contains("Add search box"),
+            "expected commit in: {text}"
+        );
+        assert!(text.contains("M src/lib.rs"), "expected change in: {text}");
+        assert!(
+            text.contains("origin/main ↑1 ↓0"),
+            "expected upstream in: {text}"
+        );
+    }
+}
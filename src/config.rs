@@ -1,35 +1,1032 @@
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
+use toml::de::{DeTable, DeValue};
+use toml::{Table, Value};
+use toml_edit::{DocumentMut, Item};
+
+use crate::errors::GrovError;
 
 const CONFIG_FILE: &str = ".grov.toml";
 
-#[derive(Default, Serialize, Deserialize)]
+/// Format version written into new config files. Files with an older (or no)
+/// `version` are migrated on load.
+pub const CONFIG_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct GrovConfig {
+    #[serde(default = "current_version")]
+    pub version: u32,
     #[serde(default)]
     pub worktree: WorktreeConfig,
+    #[serde(default, skip_serializing_if = "BranchConfig::is_empty")]
+    pub branch: BranchConfig,
+    #[serde(default, skip_serializing_if = "StatusConfig::is_default")]
+    pub status: StatusConfig,
+    #[serde(default, skip_serializing_if = "ListConfig::is_empty")]
+    pub list: ListConfig,
+    #[serde(default, skip_serializing_if = "HooksConfig::is_empty")]
+    pub hooks: HooksConfig,
+}
+
+impl Default for GrovConfig {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            worktree: WorktreeConfig::default(),
+            branch: BranchConfig::default(),
+            status: StatusConfig::default(),
+            list: ListConfig::default(),
+            hooks: HooksConfig::default(),
+        }
+    }
+}
+
+fn current_version() -> u32 {
+    CONFIG_VERSION
 }
 
 #[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct WorktreeConfig {
     #[serde(default)]
     pub prefix: String,
+    /// Worktree path relative to the project dir, e.g. `trees/{branch}`.
+    /// Empty means `<prefix>_<branch>`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub path_template: String,
+    /// Regex extracting `{ticket}` from branch names.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ticket_pattern: String,
+    /// What `grov add` does when another branch already uses the directory.
+    #[serde(default, skip_serializing_if = "CollisionStrategy::is_default")]
+    pub on_collision: CollisionStrategy,
+    /// Globs copied from an existing worktree by `grov add`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub copy_files: Vec<String>,
+    /// Globs symlinked from an existing worktree by `grov add`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub link_files: Vec<String>,
+    /// Branch globs `grov remove` refuses to remove. Unset means the
+    /// default branch; `[]` protects nothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protected: Option<Vec<String>>,
+}
+
+impl WorktreeConfig {
+    /// Whether `branch` is protected from removal, given the repo's default branch.
+    pub fn is_protected(&self, branch: &str, default_branch: Option<&str>) -> bool {
+        match &self.protected {
+            Some(patterns) => patterns.iter().any(|pattern| {
+                glob::Pattern::new(pattern).map_or(pattern == branch, |p| p.matches(branch))
+            }),
+            None => default_branch == Some(branch),
+        }
+    }
+}
+
+/// How to name a worktree whose directory is already taken, e.g. by
+/// `feature-login` when adding `feature/login`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollisionStrategy {
+    /// Refuse and explain the conflict.
+    #[default]
+    Error,
+    /// Append `-2`, `-3`, ... until the name is free.
+    Suffix,
+    /// Append a short hash of the branch name.
+    Hash,
+}
+
+impl CollisionStrategy {
+    pub const NAMES: &[&str] = &["error", "suffix", "hash"];
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Naming policy for branches created by grov.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BranchConfig {
+    /// New branch names must start with one of these, e.g. `feat/`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prefixes: Vec<String>,
+    /// Regex new branch names must contain a match for, e.g. a ticket ID.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pattern: String,
+}
+
+impl BranchConfig {
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty() && self.pattern.is_empty()
+    }
+}
+
+/// How grov reads worktree status for `list` and friends.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatusConfig {
+    /// Whether untracked files make a worktree dirty; `no` is much faster
+    /// on large repositories.
+    #[serde(default, skip_serializing_if = "UntrackedFiles::is_default")]
+    pub untracked: UntrackedFiles,
+}
+
+impl StatusConfig {
+    fn is_default(&self) -> bool {
+        self.untracked.is_default()
+    }
+}
+
+/// Defaults for `grov list`.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListConfig {
+    /// Extra columns shown without `--columns`, e.g. `["date", "upstream"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<String>,
+}
+
+impl ListConfig {
+    fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
+
+/// Values of `git status --untracked-files`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UntrackedFiles {
+    /// Untracked files count, without listing the contents of untracked dirs.
+    #[default]
+    Normal,
+    /// Ignore untracked files.
+    No,
+    /// Also look inside untracked directories.
+    All,
+}
+
+impl UntrackedFiles {
+    pub const NAMES: &[&str] = &["normal", "no", "all"];
+
+    pub fn as_str(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Shell commands run around worktree lifecycle events, in order.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HooksConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_add: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_init: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_remove: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_remove: Vec<String>,
 }
 
-/// Read `.grov.toml` from the bare repo directory.
-/// Returns `Default` if the file doesn't exist.
-pub fn read_config(bare_repo: &Path) -> GrovConfig {
-    let path = bare_repo.join(CONFIG_FILE);
-    match std::fs::read_to_string(&path) {
-        Ok(contents) => toml::from_str(&contents).unwrap_or_default(),
-        Err(_) => GrovConfig::default(),
+impl HooksConfig {
+    pub fn is_empty(&self) -> bool {
+        self.post_add.is_empty()
+            && self.post_init.is_empty()
+            && self.pre_remove.is_empty()
+            && self.post_remove.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKind {
+    String,
+    StringList,
+    /// A string restricted to the given values; the first is the default.
+    Choice(&'static [&'static str]),
+}
+
+/// Every leaf key of `GrovConfig` except `version`, in display order.
+pub const KEYS: &[(&str, KeyKind)] = &[
+    ("worktree.prefix", KeyKind::String),
+    ("worktree.path_template", KeyKind::String),
+    ("worktree.ticket_pattern", KeyKind::String),
+    (
+        "worktree.on_collision",
+        KeyKind::Choice(CollisionStrategy::NAMES),
+    ),
+    ("worktree.copy_files", KeyKind::StringList),
+    ("worktree.link_files", KeyKind::StringList),
+    ("worktree.protected", KeyKind::StringList),
+    ("branch.prefixes", KeyKind::StringList),
+    ("branch.pattern", KeyKind::String),
+    ("status.untracked", KeyKind::Choice(UntrackedFiles::NAMES)),
+    ("list.columns", KeyKind::StringList),
+    ("hooks.post_add", KeyKind::StringList),
+    ("hooks.post_init", KeyKind::StringList),
+    ("hooks.pre_remove", KeyKind::StringList),
+    ("hooks.post_remove", KeyKind::StringList),
+];
+
+/// Environment variable overriding `key`: `worktree.prefix` → `GROV_WORKTREE_PREFIX`.
+pub fn env_var_name(key: &str) -> String {
+    format!("GROV_{}", key.replace('.', "_").to_uppercase())
+}
+
+/// Where the effective value of a config key came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    Default,
+    Global(PathBuf),
+    Repo(PathBuf),
+    Env(String),
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigOrigin::Default => write!(f, "default"),
+            ConfigOrigin::Global(path) => write!(f, "global:{}", path.display()),
+            ConfigOrigin::Repo(path) => write!(f, "repo:{}", path.display()),
+            ConfigOrigin::Env(var) => write!(f, "env:{var}"),
+        }
+    }
+}
+
+/// The merged config together with the origin of every key that was set.
+pub struct LoadedConfig {
+    pub config: GrovConfig,
+    /// Merged values keyed by dotted key, before deserialization.
+    pub values: BTreeMap<String, Value>,
+    pub origins: BTreeMap<String, ConfigOrigin>,
+}
+
+impl LoadedConfig {
+    pub fn origin(&self, key: &str) -> &ConfigOrigin {
+        self.origins.get(key).unwrap_or(&ConfigOrigin::Default)
+    }
+
+    /// Effective value of a known key, falling back to its default.
+    pub fn value(&self, key: &str, kind: KeyKind) -> Value {
+        self.values.get(key).cloned().unwrap_or(match kind {
+            KeyKind::String => Value::String(String::new()),
+            KeyKind::StringList => Value::Array(Vec::new()),
+            KeyKind::Choice(choices) => Value::String(choices[0].to_string()),
+        })
+    }
+}
+
+/// Path of the user-level config: `$XDG_CONFIG_HOME/grov/config.toml`,
+/// falling back to `~/.config/grov/config.toml`.
+pub fn global_config_path() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("grov").join("config.toml"))
+}
+
+/// Path of the per-project config inside the bare repo.
+pub fn repo_config_path(bare_repo: &Path) -> PathBuf {
+    bare_repo.join(CONFIG_FILE)
+}
+
+/// Read `.grov.toml` from the bare repo directory, layered over the global
+/// config and under `GROV_*` environment overrides.
+/// Returns `Default` for anything not set, and an error for malformed files.
+pub fn read_config(bare_repo: &Path) -> Result<GrovConfig, GrovError> {
+    Ok(load_config(Some(bare_repo))?.config)
+}
+
+/// Load the effective config: global file, then the repo file (if any),
+/// then environment overrides. Later layers win per key; lists are replaced,
+/// not appended.
+pub fn load_config(bare_repo: Option<&Path>) -> Result<LoadedConfig, GrovError> {
+    let global = global_config_path();
+    let repo = bare_repo.map(repo_config_path);
+    load_layers(global.as_deref(), repo.as_deref(), |var| {
+        std::env::var(var).ok()
+    })
+}
+
+/// Load only the user-level file, without the repo file or environment.
+pub fn load_global_config() -> Result<LoadedConfig, GrovError> {
+    load_layers(global_config_path().as_deref(), None, |_| None)
+}
+
+fn load_layers(
+    global: Option<&Path>,
+    repo: Option<&Path>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<LoadedConfig, GrovError> {
+    let mut values = BTreeMap::new();
+    let mut origins = BTreeMap::new();
+
+    let files = [
+        global.map(|p| (p, ConfigOrigin::Global(p.to_path_buf()))),
+        repo.map(|p| (p, ConfigOrigin::Repo(p.to_path_buf()))),
+    ];
+    for (path, origin) in files.into_iter().flatten() {
+        let Some(contents) = read_if_exists(path)? else {
+            continue;
+        };
+        let table = parse_layer(path, &contents).map_err(|mut errors| errors.remove(0))?;
+        let mut leaves = Vec::new();
+        flatten("", table, &mut leaves);
+        for (key, value) in leaves {
+            origins.insert(key.clone(), origin.clone());
+            values.insert(key, value);
+        }
+    }
+
+    for &(key, kind) in KEYS {
+        let var = env_var_name(key);
+        if let Some(raw) = env(&var) {
+            if let Some(message) = check_choice(key, kind, &raw) {
+                return Err(GrovError::InvalidEnvOverride { var, message });
+            }
+            let value = parse_env_value(&raw, kind);
+            if let Some(message) = check_list(key, &value) {
+                return Err(GrovError::InvalidEnvOverride { var, message });
+            }
+            values.insert(key.to_string(), value);
+            origins.insert(key.to_string(), ConfigOrigin::Env(var));
+        }
+    }
+
+    let config = Value::Table(unflatten(&values))
+        .try_into()
+        .expect("validated config layers always deserialize");
+    Ok(LoadedConfig {
+        config,
+        values,
+        origins,
+    })
+}
+
+/// Check a config file without loading it. Returns every problem found;
+/// a missing file is not an error.
+pub fn validate_file(path: &Path) -> Result<(), Vec<GrovError>> {
+    match read_if_exists(path) {
+        Ok(Some(contents)) => parse_layer(path, &contents).map(|_| ()),
+        Ok(None) => Ok(()),
+        Err(e) => Err(vec![e]),
+    }
+}
+
+fn read_if_exists(path: &Path) -> Result<Option<String>, GrovError> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Parse and validate one config file, migrating it to `CONFIG_VERSION`.
+/// The returned table has no `version` key.
+fn parse_layer(path: &Path, contents: &str) -> Result<Table, Vec<GrovError>> {
+    let error = |span: std::ops::Range<usize>, key: Option<&str>, message: String| {
+        let (line, column) = line_column(contents, span.start);
+        GrovError::InvalidConfig {
+            path: path.to_path_buf(),
+            line,
+            column,
+            key: key.map(str::to_string),
+            message,
+        }
+    };
+
+    let document = DeTable::parse(contents).map_err(|e| {
+        vec![error(
+            e.span().unwrap_or(0..0),
+            None,
+            e.message().trim().to_string(),
+        )]
+    })?;
+
+    let mut problems = Vec::new();
+    let mut version = 0;
+    check_table("", document.get_ref(), &mut version, &mut problems);
+    if !problems.is_empty() {
+        return Err(problems
+            .into_iter()
+            .map(|(span, key, message)| error(span, Some(&key), message))
+            .collect());
+    }
+
+    let mut table: Table = contents.parse().expect("document already parsed as TOML");
+    migrate(&mut table, version);
+    Ok(table)
+}
+
+type Problem = (std::ops::Range<usize>, String, String);
+
+/// Check every key of `table` against `KEYS`, recording the file's `version`.
+fn check_table(prefix: &str, table: &DeTable, version: &mut i64, problems: &mut Vec<Problem>) {
+    for (name, value) in table.iter() {
+        let key = if prefix.is_empty() {
+            name.get_ref().to_string()
+        } else {
+            format!("{prefix}.{}", name.get_ref())
+        };
+        let is_section = KEYS.iter().any(|(k, _)| {
+            k.strip_prefix(key.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+        });
+
+        let problem = match value.get_ref() {
+            DeValue::Table(inner) if is_section => {
+                check_table(&key, inner, version, problems);
+                None
+            }
+            _ if is_section => Some((value.span(), "expected a table".to_string())),
+            DeValue::Integer(n) if key == "version" => {
+                let parsed = i64::from_str_radix(&n.as_str().replace('_', ""), n.radix());
+                match parsed {
+                    Ok(v) if (0..=i64::from(CONFIG_VERSION)).contains(&v) => {
+                        *version = v;
+                        None
+                    }
+                    Ok(v) if v > i64::from(CONFIG_VERSION) => Some((
+                        value.span(),
+                        format!(
+                            "config version {v} is newer than this grov supports \
+                             ({CONFIG_VERSION}); upgrade grov"
+                        ),
+                    )),
+                    _ => Some((value.span(), "invalid config version".to_string())),
+                }
+            }
+            other if key == "version" => Some((
+                value.span(),
+                format!("expected an integer, found {}", other.type_str()),
+            )),
+            other => match KEYS.iter().find(|(k, _)| *k == key).map(|(_, kind)| *kind) {
+                None => Some((name.span(), format!("unknown key `{key}`"))),
+                Some(KeyKind::String) if !other.is_str() => Some((
+                    value.span(),
+                    format!("`{key}` expects a string, found {}", other.type_str()),
+                )),
+                Some(KeyKind::Choice(_)) if !other.is_str() => Some((
+                    value.span(),
+                    format!("`{key}` expects a string, found {}", other.type_str()),
+                )),
+                Some(kind @ KeyKind::Choice(_)) => {
+                    check_choice(&key, kind, other.as_str().unwrap_or_default())
+                        .map(|message| (value.span(), message))
+                }
+                Some(KeyKind::StringList)
+                    if !other
+                        .as_array()
+                        .is_some_and(|items| items.iter().all(|v| v.get_ref().is_str())) =>
+                {
+                    Some((value.span(), format!("`{key}` expects a list of strings")))
+                }
+                Some(_) => None,
+            },
+        };
+        if let Some((span, message)) = problem {
+            problems.push((span, key, message));
+        }
+    }
+}
+
+/// Bring a table written with format `from` up to `CONFIG_VERSION`, one
+/// step at a time.
+fn migrate(table: &mut Table, from: i64) {
+    for version in from..i64::from(CONFIG_VERSION) {
+        match version {
+            // Files from before `version` existed share the version 1 layout
+            0 => {}
+            _ => unreachable!("no migration from config version {version}"),
+        }
+    }
+    table.remove("version");
+}
+
+/// 1-based line and column of a byte offset.
+fn line_column(contents: &str, offset: usize) -> (usize, usize) {
+    let before = &contents[..offset.min(contents.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+/// Collect `(dotted.key, value)` pairs for every non-table value.
+fn flatten(prefix: &str, table: Table, out: &mut Vec<(String, Value)>) {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}.{name}")
+        };
+        match value {
+            Value::Table(inner) => flatten(&key, inner, out),
+            other => out.push((key, other)),
+        }
+    }
+}
+
+fn unflatten(values: &BTreeMap<String, Value>) -> Table {
+    let mut root = Table::new();
+    for (key, value) in values {
+        let mut table = &mut root;
+        let mut parts = key.split('.').peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                table.insert(part.to_string(), value.clone());
+                break;
+            }
+            let entry = table
+                .entry(part.to_string())
+                .or_insert_with(|| Value::Table(Table::new()));
+            let Value::Table(inner) = entry else {
+                break;
+            };
+            table = inner;
+        }
+    }
+    root
+}
+
+/// Error message when `raw` is not one of the values a choice key allows.
+fn check_choice(key: &str, kind: KeyKind, raw: &str) -> Option<String> {
+    match kind {
+        KeyKind::Choice(choices) if !choices.contains(&raw) => Some(format!(
+            "`{key}` must be one of {}, found `{raw}`",
+            choices.join(", ")
+        )),
+        _ => None,
+    }
+}
+
+/// Error message when a list value has items that are not strings.
+fn check_list(key: &str, value: &Value) -> Option<String> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .find(|item| !item.is_str())
+            .map(|item| format!("`{key}` must be a list of strings, found `{item}`")),
+        _ => None,
+    }
+}
+
+/// List values may be given as a TOML array (`["a", "b"]`); anything else
+/// is a single-item list.
+fn parse_env_value(raw: &str, kind: KeyKind) -> Value {
+    match kind {
+        KeyKind::String | KeyKind::Choice(_) => Value::String(raw.to_string()),
+        KeyKind::StringList => {
+            let parsed = raw
+                .trim_start()
+                .starts_with('[')
+                .then(|| format!("v = {raw}").parse::<Table>().ok())
+                .flatten()
+                .and_then(|mut t| t.remove("v"));
+            match parsed {
+                Some(array @ Value::Array(_)) => array,
+                _ => Value::Array(vec![Value::String(raw.to_string())]),
+            }
+        }
+    }
+}
+
+/// Look up a known key, listing the valid ones if it is not.
+pub fn key_kind(key: &str) -> anyhow::Result<KeyKind> {
+    match KEYS.iter().find(|(k, _)| *k == key) {
+        Some(&(_, kind)) => Ok(kind),
+        None => {
+            let known: Vec<&str> = KEYS.iter().map(|(k, _)| *k).collect();
+            anyhow::bail!("unknown key `{key}` (known keys: {})", known.join(", "))
+        }
+    }
+}
+
+/// Turn command-line values into a value for `key`: strings take exactly
+/// one value, lists take one or more (or a single TOML array).
+pub fn parse_cli_value(key: &str, kind: KeyKind, values: &[String]) -> anyhow::Result<Value> {
+    match (kind, values) {
+        (_, []) => anyhow::bail!("`{key}` needs a value"),
+        (_, [single]) => {
+            if let Some(message) = check_choice(key, kind, single) {
+                anyhow::bail!(message);
+            }
+            let value = parse_env_value(single, kind);
+            if let Some(message) = check_list(key, &value) {
+                anyhow::bail!(message);
+            }
+            Ok(value)
+        }
+        (KeyKind::String | KeyKind::Choice(_), _) => {
+            anyhow::bail!("`{key}` takes a single value")
+        }
+        (KeyKind::StringList, many) => Ok(Value::Array(
+            many.iter().cloned().map(Value::String).collect(),
+        )),
+    }
+}
+
+/// Set `key` to `value` (or remove it when `None`) in the config file at
+/// `path`, keeping comments and key order. Creates the file if needed.
+/// Returns `false` when removing a key that was not set.
+pub fn edit_config_file(path: &Path, key: &str, value: Option<&Value>) -> anyhow::Result<bool> {
+    let existing = read_if_exists(path)?;
+    if let Some(contents) = &existing {
+        parse_layer(path, contents)
+            .map_err(|mut errors| errors.remove(0))
+            .context("fix the config file before editing it")?;
+    }
+
+    let mut doc: DocumentMut = existing.as_deref().unwrap_or_default().parse()?;
+    if existing.is_none() {
+        doc["version"] = toml_edit::value(i64::from(CONFIG_VERSION));
+    }
+
+    let (section, leaf) = key.split_once('.').expect("config keys are dotted");
+    match value {
+        Some(value) => {
+            // A `[section]` header rather than an inline table
+            if !doc.contains_key(section) {
+                doc[section] = toml_edit::table();
+            }
+            doc[section][leaf] = toml_edit::value(to_edit_value(value));
+        }
+        None => {
+            let Some(table) = doc.get_mut(section).and_then(Item::as_table_like_mut) else {
+                return Ok(false);
+            };
+            if table.remove(leaf).is_none() {
+                return Ok(false);
+            }
+            if table.is_empty() {
+                doc.remove(section);
+            }
+        }
+    }
+
+    let contents = doc.to_string();
+    parse_layer(path, &contents).map_err(|mut errors| errors.remove(0))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)?;
+    Ok(true)
+}
+
+fn to_edit_value(value: &Value) -> toml_edit::Value {
+    match value {
+        Value::String(s) => s.as_str().into(),
+        Value::Array(items) => toml_edit::Value::Array(
+            items
+                .iter()
+                .map(to_edit_value)
+                .collect::<toml_edit::Array>(),
+        ),
+        other => unreachable!("config values are strings or lists, got {other}"),
     }
 }
 
 /// Write `.grov.toml` into the bare repo directory.
 pub fn write_config(bare_repo: &Path, config: &GrovConfig) -> anyhow::Result<()> {
-    let path = bare_repo.join(CONFIG_FILE);
+    let path = repo_config_path(bare_repo);
     let contents = toml::to_string_pretty(config)?;
     std::fs::write(path, contents)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn keys_cover_every_config_field() {
+        let config = GrovConfig {
+            version: CONFIG_VERSION,
+            worktree: WorktreeConfig {
+                prefix: "p".into(),
+                path_template: "t/{branch}".into(),
+                ticket_pattern: "T-\\d+".into(),
+                on_collision: CollisionStrategy::Suffix,
+                copy_files: vec!["a".into()],
+                link_files: vec!["b".into()],
+                protected: Some(vec!["main".into()]),
+            },
+            branch: BranchConfig {
+                prefixes: vec!["feat/".into()],
+                pattern: "T-\\d+".into(),
+            },
+            status: StatusConfig {
+                untracked: UntrackedFiles::No,
+            },
+            list: ListConfig {
+                columns: vec!["date".into()],
+            },
+            hooks: HooksConfig {
+                post_add: vec!["c".into()],
+                post_init: vec!["d".into()],
+                pre_remove: vec!["e".into()],
+                post_remove: vec!["f".into()],
+            },
+        };
+        let Value::Table(table) = Value::try_from(&config).unwrap() else {
+            panic!("config must serialize to a table");
+        };
+        let mut leaves = Vec::new();
+        flatten("", table, &mut leaves);
+        let mut serialized: Vec<String> = leaves
+            .into_iter()
+            .map(|(k, _)| k)
+            .filter(|k| k != "version")
+            .collect();
+        serialized.sort();
+        let mut known: Vec<String> = KEYS.iter().map(|(k, _)| k.to_string()).collect();
+        known.sort();
+        assert_eq!(serialized, known);
+    }
+
+    #[test]
+    fn repo_overrides_global_per_key() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let global = write(
+            tmp.path(),
+            "global.toml",
+            "[worktree]\nprefix = \"g\"\n[hooks]\npost_add = [\"global\"]\n",
+        );
+        let repo = write(tmp.path(), "repo.toml", "[worktree]\nprefix = \"r\"\n");
+
+        let loaded = load_layers(Some(&global), Some(&repo), |_| None).unwrap();
+        assert_eq!(loaded.config.worktree.prefix, "r");
+        assert_eq!(loaded.config.hooks.post_add, vec!["global"]);
+        assert_eq!(
+            loaded.origin("worktree.prefix"),
+            &ConfigOrigin::Repo(repo.clone())
+        );
+        assert_eq!(
+            loaded.origin("hooks.post_add"),
+            &ConfigOrigin::Global(global.clone())
+        );
+        assert_eq!(loaded.origin("hooks.pre_remove"), &ConfigOrigin::Default);
+    }
+
+    #[test]
+    fn env_overrides_files() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let repo = write(tmp.path(), "repo.toml", "[worktree]\nprefix = \"r\"\n");
+
+        let loaded = load_layers(None, Some(&repo), |var| match var {
+            "GROV_WORKTREE_PREFIX" => Some("e".to_string()),
+            "GROV_HOOKS_POST_ADD" => Some("[\"one\", \"two\"]".to_string()),
+            "GROV_WORKTREE_COPY_FILES" => Some(".env".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(loaded.config.worktree.prefix, "e");
+        assert_eq!(loaded.config.hooks.post_add, vec!["one", "two"]);
+        assert_eq!(loaded.config.worktree.copy_files, vec![".env"]);
+        assert_eq!(
+            loaded.origin("worktree.prefix"),
+            &ConfigOrigin::Env("GROV_WORKTREE_PREFIX".to_string())
+        );
+    }
+
+    #[test]
+    fn env_rejects_non_string_list_items() {
+        let result = load_layers(None, None, |var| {
+            (var == "GROV_HOOKS_POST_ADD").then(|| "[1, 2]".to_string())
+        });
+        assert!(matches!(
+            result,
+            Err(GrovError::InvalidEnvOverride { var, .. }) if var == "GROV_HOOKS_POST_ADD"
+        ));
+    }
+
+    #[test]
+    fn missing_files_give_defaults() {
+        let loaded = load_layers(
+            Some(Path::new("/nonexistent/global.toml")),
+            Some(Path::new("/nonexistent/.grov.toml")),
+            |_| None,
+        )
+        .unwrap();
+        assert_eq!(loaded.config.worktree.prefix, "");
+        assert!(loaded.origins.is_empty());
+        assert_eq!(
+            loaded.value("worktree.copy_files", KeyKind::StringList),
+            Value::Array(vec![])
+        );
+    }
+
+    fn invalid(contents: &str) -> Vec<(usize, usize, Option<String>, String)> {
+        parse_layer(Path::new("/p/.grov.toml"), contents)
+            .unwrap_err()
+            .into_iter()
+            .map(|e| match e {
+                GrovError::InvalidConfig {
+                    line,
+                    column,
+                    key,
+                    message,
+                    ..
+                } => (line, column, key, message),
+                other => panic!("unexpected error: {other}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rejects_unknown_keys_with_position() {
+        let errors =
+            invalid("[worktree]\nprefix = \"p\"\nprefx = \"q\"\n\n[theme]\nname = \"x\"\n");
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    5,
+                    2,
+                    Some("theme".to_string()),
+                    "unknown key `theme`".to_string()
+                ),
+                (
+                    3,
+                    1,
+                    Some("worktree.prefx".to_string()),
+                    "unknown key `worktree.prefx`".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_types() {
+        let errors = invalid("worktree = 3\n[hooks]\npost_add = \"npm ci\"\n");
+        let messages: Vec<&str> = errors.iter().map(|e| e.3.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "`hooks.post_add` expects a list of strings",
+                "expected a table"
+            ]
+        );
+        assert_eq!(errors[0].0, 3);
+        assert_eq!(errors[0].1, 12);
+    }
+
+    #[test]
+    fn reports_syntax_errors() {
+        let errors = invalid("[worktree]\nprefix = \n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 2);
+        assert_eq!(errors[0].2, None);
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let errors = invalid("version = 99\n");
+        assert!(errors[0].3.contains("newer than this grov supports"));
+        assert_eq!(errors[0].2.as_deref(), Some("version"));
+    }
+
+    #[test]
+    fn migrates_unversioned_files() {
+        let table =
+            parse_layer(Path::new("/p/.grov.toml"), "[worktree]\nprefix = \"p\"\n").unwrap();
+        assert!(!table.contains_key("version"));
+        let versioned = parse_layer(
+            Path::new("/p/.grov.toml"),
+            "version = 1\n[worktree]\nprefix = \"p\"\n",
+        )
+        .unwrap();
+        assert_eq!(table, versioned);
+    }
+
+    #[test]
+    fn load_fails_on_malformed_repo_file() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let repo = write(tmp.path(), "repo.toml", "[worktree]\nprefx = \"typo\"\n");
+        let err = load_layers(None, Some(&repo), |_| None).err().unwrap();
+        assert!(
+            err.to_string()
+                .ends_with("repo.toml:2:1: unknown key `worktree.prefx`")
+        );
+    }
+
+    #[test]
+    fn edit_preserves_comments_and_order() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = write(
+            tmp.path(),
+            ".grov.toml",
+            "# project settings\nversion = 1\n\n[worktree]\n# short prefix\nprefix = \"p\"\ncopy_files = [\".env\"]\n",
+        );
+
+        let value = Value::String("q".to_string());
+        assert!(edit_config_file(&path, "worktree.prefix", Some(&value)).unwrap());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "# project settings\nversion = 1\n\n[worktree]\n# short prefix\nprefix = \"q\"\ncopy_files = [\".env\"]\n"
+        );
+    }
+
+    #[test]
+    fn edit_creates_file_with_version() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("grov/config.toml");
+        let value = Value::Array(vec![Value::String("npm ci".to_string())]);
+        edit_config_file(&path, "hooks.post_add", Some(&value)).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "version = 1\n\n[hooks]\npost_add = [\"npm ci\"]\n"
+        );
+    }
+
+    #[test]
+    fn unset_removes_key_and_empty_section() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = write(
+            tmp.path(),
+            ".grov.toml",
+            "version = 1\n\n[hooks]\npost_add = [\"x\"]\n",
+        );
+        assert!(edit_config_file(&path, "hooks.post_add", None).unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "version = 1\n");
+        assert!(!edit_config_file(&path, "hooks.post_add", None).unwrap());
+    }
+
+    #[test]
+    fn edit_refuses_malformed_files() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = write(tmp.path(), ".grov.toml", "[worktree]\nprefx = \"p\"\n");
+        let value = Value::String("q".to_string());
+        let err = edit_config_file(&path, "worktree.prefix", Some(&value)).unwrap_err();
+        assert!(format!("{err:#}").contains("fix the config file before editing it"));
+    }
+
+    #[test]
+    fn cli_values_are_type_checked() {
+        let one = |v: &str| vec![v.to_string()];
+        assert_eq!(
+            parse_cli_value("worktree.prefix", KeyKind::String, &one("p")).unwrap(),
+            Value::String("p".to_string())
+        );
+        assert!(
+            parse_cli_value(
+                "worktree.prefix",
+                KeyKind::String,
+                &["a".to_string(), "b".to_string()]
+            )
+            .is_err()
+        );
+        assert_eq!(
+            parse_cli_value(
+                "hooks.post_add",
+                KeyKind::StringList,
+                &["a".to_string(), "b".to_string()]
+            )
+            .unwrap(),
+            Value::Array(vec![
+                Value::String("a".to_string()),
+                Value::String("b".to_string())
+            ])
+        );
+        assert!(parse_cli_value("hooks.post_add", KeyKind::StringList, &one("[1]")).is_err());
+        assert!(key_kind("worktree.prefx").is_err());
+    }
+
+    #[test]
+    fn protected_defaults_to_default_branch() {
+        let mut worktree = WorktreeConfig::default();
+        assert!(worktree.is_protected("main", Some("main")));
+        assert!(!worktree.is_protected("feature", Some("main")));
+        assert!(!worktree.is_protected("main", None));
+
+        worktree.protected = Some(vec!["release/*".into(), "develop".into()]);
+        assert!(worktree.is_protected("release/1.0", Some("main")));
+        assert!(worktree.is_protected("develop", Some("main")));
+        assert!(!worktree.is_protected("main", Some("main")));
+
+        worktree.protected = Some(vec![]);
+        assert!(!worktree.is_protected("main", Some("main")));
+    }
+
+    #[test]
+    fn env_var_names() {
+        assert_eq!(env_var_name("worktree.prefix"), "GROV_WORKTREE_PREFIX");
+        assert_eq!(env_var_name("hooks.pre_remove"), "GROV_HOOKS_PRE_REMOVE");
+    }
+}
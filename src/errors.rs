@@ -20,12 +20,28 @@ pub enum GrovError {
     #[error("branch not found: {0}")]
     BranchNotFound(String),
 
-    #[error("invalid branch name: {0}")]
-    InvalidBranchName(String),
+    #[error("invalid branch name `{name}`: {reason}")]
+    InvalidBranchName { name: String, reason: String },
+
+    #[error("{}:{line}:{column}: {message}", path.display())]
+    InvalidConfig {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        /// Dotted key the problem is attached to, when there is one.
+        key: Option<String>,
+        message: String,
+    },
+
+    #[error("{var}: {message}")]
+    InvalidEnvOverride { var: String, message: String },
 
     #[error("git command failed: {0}")]
     GitCommandFailed(String),
 
+    #[error("git {0} timed out")]
+    GitTimeout(String),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
@@ -0,0 +1,208 @@
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Context;
+use glob::{MatchOptions, Pattern};
+
+/// What happened when carrying configured files into a new worktree.
+/// Paths are relative to the worktree root.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CarryReport {
+    pub copied: Vec<PathBuf>,
+    pub linked: Vec<PathBuf>,
+    /// Patterns that matched nothing in the source worktree.
+    pub skipped: Vec<String>,
+    /// Matches that already exist in the new worktree and were left alone.
+    pub conflicts: Vec<PathBuf>,
+}
+
+impl CarryReport {
+    pub fn is_empty(&self) -> bool {
+        self.copied.is_empty()
+            && self.linked.is_empty()
+            && self.skipped.is_empty()
+            && self.conflicts.is_empty()
+    }
+}
+
+/// Copy files matching `copy` and symlink files matching `link` from the
+/// `source` worktree into `dest`.
+///
+/// Patterns are globs relative to the worktree root. Existing files in
+/// `dest` are never overwritten. A path matched by both lists is copied.
+pub fn carry_files(
+    source: &Path,
+    dest: &Path,
+    copy: &[String],
+    link: &[String],
+) -> anyhow::Result<CarryReport> {
+    let mut report = CarryReport::default();
+    let mut handled = BTreeSet::new();
+
+    for (patterns, symlink) in [(copy, false), (link, true)] {
+        for pattern in patterns {
+            let matches = glob_relative(source, pattern)?;
+            if matches.is_empty() {
+                report.skipped.push(pattern.clone());
+                continue;
+            }
+            for rel in matches {
+                // Skip paths already carried, or inside a carried directory
+                if rel.ancestors().any(|a| handled.contains(a)) {
+                    continue;
+                }
+                handled.insert(rel.clone());
+
+                let target = dest.join(&rel);
+                if target.symlink_metadata().is_ok() {
+                    report.conflicts.push(rel);
+                    continue;
+                }
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let from = source.join(&rel);
+                if symlink {
+                    std::os::unix::fs::symlink(&from, &target)
+                        .with_context(|| format!("could not link {}", rel.display()))?;
+                    report.linked.push(rel);
+                } else {
+                    copy_recursive(&from, &target)
+                        .with_context(|| format!("could not copy {}", rel.display()))?;
+                    report.copied.push(rel);
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Expand `pattern` inside `root`, returning sorted paths relative to `root`.
+fn glob_relative(root: &Path, pattern: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let pattern_path = Path::new(pattern);
+    if pattern_path
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        anyhow::bail!("file pattern must stay inside the worktree: {pattern}");
+    }
+
+    let root_str = root
+        .to_str()
+        .with_context(|| format!("non-UTF-8 worktree path: {}", root.display()))?;
+    let full = format!("{}/{pattern}", Pattern::escape(root_str));
+    let options = MatchOptions {
+        require_literal_leading_dot: false,
+        ..MatchOptions::new()
+    };
+    let paths = glob::glob_with(&full, options)
+        .with_context(|| format!("invalid file pattern: {pattern}"))?;
+
+    let mut matches: Vec<PathBuf> = paths
+        .filter_map(Result::ok)
+        .filter_map(|p| p.strip_prefix(root).ok().map(Path::to_path_buf))
+        // The worktree's own `.git` link is never carried over
+        .filter(|rel| rel.components().next() != Some(Component::Normal(".git".as_ref())))
+        .filter(|rel| !rel.as_os_str().is_empty())
+        .collect();
+    matches.sort();
+    Ok(matches)
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> std::io::Result<()> {
+    if from.is_dir() {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        std::fs::copy(from, to).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (tempfile::TempDir, PathBuf, PathBuf) {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let source = tmp.path().join("source");
+        let dest = tmp.path().join("dest");
+        std::fs::create_dir_all(source.join("config")).unwrap();
+        std::fs::create_dir_all(&dest).unwrap();
+        std::fs::write(source.join(".git"), "gitdir: elsewhere\n").unwrap();
+        std::fs::write(source.join(".env"), "SECRET=1\n").unwrap();
+        std::fs::write(source.join(".env.local"), "LOCAL=1\n").unwrap();
+        std::fs::write(source.join("config/master.key"), "key\n").unwrap();
+        (tmp, source, dest)
+    }
+
+    fn strings(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn copies_glob_matches() {
+        let (_tmp, source, dest) = setup();
+        let report = carry_files(&source, &dest, &strings(&[".env*"]), &[]).unwrap();
+        assert_eq!(
+            report.copied,
+            vec![PathBuf::from(".env"), PathBuf::from(".env.local")]
+        );
+        assert_eq!(
+            std::fs::read_to_string(dest.join(".env")).unwrap(),
+            "SECRET=1\n"
+        );
+    }
+
+    #[test]
+    fn links_into_nested_dirs() {
+        let (_tmp, source, dest) = setup();
+        let report = carry_files(&source, &dest, &[], &strings(&["config/master.key"])).unwrap();
+        assert_eq!(report.linked, vec![PathBuf::from("config/master.key")]);
+        let target = std::fs::read_link(dest.join("config/master.key")).unwrap();
+        assert_eq!(target, source.join("config/master.key"));
+    }
+
+    #[test]
+    fn reports_unmatched_and_conflicting() {
+        let (_tmp, source, dest) = setup();
+        std::fs::write(dest.join(".env"), "mine\n").unwrap();
+        let report = carry_files(&source, &dest, &strings(&[".env", "missing.txt"]), &[]).unwrap();
+        assert_eq!(report.conflicts, vec![PathBuf::from(".env")]);
+        assert_eq!(report.skipped, vec!["missing.txt".to_string()]);
+        assert!(report.copied.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(dest.join(".env")).unwrap(),
+            "mine\n"
+        );
+    }
+
+    #[test]
+    fn never_carries_dot_git() {
+        let (_tmp, source, dest) = setup();
+        let report = carry_files(&source, &dest, &strings(&["*"]), &[]).unwrap();
+        assert!(!report.copied.contains(&PathBuf::from(".git")));
+        assert!(report.copied.contains(&PathBuf::from("config")));
+        assert!(dest.join("config/master.key").exists());
+    }
+
+    #[test]
+    fn copy_wins_over_link() {
+        let (_tmp, source, dest) = setup();
+        let report = carry_files(&source, &dest, &strings(&[".env"]), &strings(&[".env"])).unwrap();
+        assert_eq!(report.copied, vec![PathBuf::from(".env")]);
+        assert!(report.linked.is_empty());
+        assert!(report.conflicts.is_empty());
+    }
+
+    #[test]
+    fn rejects_patterns_escaping_the_worktree() {
+        let (_tmp, source, dest) = setup();
+        assert!(carry_files(&source, &dest, &strings(&["../secrets"]), &[]).is_err());
+        assert!(carry_files(&source, &dest, &strings(&["/etc/passwd"]), &[]).is_err());
+    }
+}
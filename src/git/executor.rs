@@ -1,5 +1,8 @@
+use std::io::Read;
 use std::path::Path;
-use std::process::{Command, ExitStatus};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 use crate::errors::GrovError;
 
@@ -44,3 +47,80 @@ pub fn run_git_ok(repo_path: Option<&Path>, args: &[&str]) -> crate::errors::Res
         Err(GrovError::GitCommandFailed(msg))
     }
 }
+
+/// Like `run_git`, but kill git and fail with `GrovError::GitTimeout` when it
+/// runs longer than `timeout`.
+pub fn run_git_with_timeout(
+    repo_path: Option<&Path>,
+    args: &[&str],
+    timeout: Duration,
+) -> crate::errors::Result<GitOutput> {
+    let mut cmd = Command::new("git");
+
+    if let Some(path) = repo_path {
+        cmd.env("GIT_DIR", path);
+    }
+
+    let mut child = cmd
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            // Readers are left to finish on their own: processes spawned by
+            // git may keep the pipes open after git itself is gone
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(GrovError::GitTimeout(args.join(" ")));
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    };
+
+    let collect = |reader: JoinHandle<String>| reader.join().unwrap_or_default().trim().to_string();
+    Ok(GitOutput {
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+        status,
+    })
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_returns_output_of_fast_commands() {
+        let output = run_git_with_timeout(None, &["--version"], Duration::from_secs(10)).unwrap();
+        assert!(output.status.success());
+        assert!(output.stdout.starts_with("git version"));
+    }
+
+    #[test]
+    fn timeout_kills_slow_commands() {
+        let start = Instant::now();
+        let result = run_git_with_timeout(
+            None,
+            &["-c", "alias.slow=!sleep 5", "slow"],
+            Duration::from_millis(100),
+        );
+        assert!(matches!(result, Err(GrovError::GitTimeout(_))));
+        assert!(start.elapsed() < Duration::from_secs(4));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::errors::Result;
+use crate::git::executor::{run_git, run_git_ok};
+
+/// The parts of a commit shown alongside a branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitInfo {
+    pub subject: String,
+    pub author: String,
+    /// Committer date as a unix timestamp.
+    pub time: i64,
+}
+
+/// Look up several commits with a single `git show`, keyed by full SHA.
+pub fn commit_infos(repo: &Path, shas: &[&str]) -> Result<HashMap<String, CommitInfo>> {
+    if shas.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut args = vec!["show", "-s", "--format=%H%x1f%ct%x1f%an%x1f%s"];
+    args.extend_from_slice(shas);
+    let output = run_git_ok(Some(repo), &args)?;
+    Ok(output.lines().filter_map(parse_commit_line).collect())
+}
+
+fn parse_commit_line(line: &str) -> Option<(String, CommitInfo)> {
+    let mut fields = line.splitn(4, '\x1f');
+    let sha = fields.next()?;
+    let time = fields.next()?.parse().ok()?;
+    let author = fields.next()?;
+    let subject = fields.next().unwrap_or_default();
+    Some((
+        sha.to_string(),
+        CommitInfo {
+            subject: subject.to_string(),
+            author: author.to_string(),
+            time,
+        },
+    ))
+}
+
+/// A commit as shown in a short log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub short_sha: String,
+    pub subject: String,
+    /// Committer date as a unix timestamp.
+    pub time: i64,
+}
+
+const LOG_LINE_FORMAT: &str = "--format=%h%x1f%ct%x1f%s";
+
+fn parse_log_line(line: &str) -> Option<LogLine> {
+    let mut fields = line.splitn(3, '\x1f');
+    Some(LogLine {
+        short_sha: fields.next()?.to_string(),
+        time: fields.next()?.parse().ok()?,
+        subject: fields.next().unwrap_or_default().to_string(),
+    })
+}
+
+/// The last `limit` commits of the worktree's HEAD, newest first.
+pub fn recent_commits(worktree_path: &Path, limit: usize) -> Result<Vec<LogLine>> {
+    let path_str = worktree_path.to_string_lossy();
+    let limit = format!("--max-count={limit}");
+    let output = run_git_ok(
+        None,
+        &["-C", &path_str, "log", &limit, LOG_LINE_FORMAT, "HEAD"],
+    )?;
+    Ok(output.lines().filter_map(parse_log_line).collect())
+}
+
+/// Up to `limit` commits reachable from `tip` but not from `base`, newest
+/// first, resolved in the bare repository.
+pub fn commits_between(repo: &Path, base: &str, tip: &str, limit: usize) -> Result<Vec<LogLine>> {
+    let limit = format!("--max-count={limit}");
+    let range = format!("{base}..{tip}");
+    let output = run_git_ok(Some(repo), &["log", &limit, LOG_LINE_FORMAT, &range, "--"])?;
+    Ok(output.lines().filter_map(parse_log_line).collect())
+}
+
+/// Short age of a commit made at `time`, e.g. `3d ago`, relative to `now`.
+pub fn relative_age(time: i64, now: i64) -> String {
+    const UNITS: [(i64, &str); 6] = [
+        (365 * 86_400, "y"),
+        (30 * 86_400, "mo"),
+        (7 * 86_400, "w"),
+        (86_400, "d"),
+        (3_600, "h"),
+        (60, "m"),
+    ];
+    let elapsed = (now - time).max(0);
+    UNITS.iter().find(|(secs, _)| elapsed >= *secs).map_or_else(
+        || "just now".to_string(),
+        |(secs, unit)| format!("{}{unit} ago", elapsed / secs),
+    )
+}
+
+/// Current unix time, for [`relative_age`].
+pub fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// One entry of `git stash list`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StashEntry {
+    /// e.g. `stash@{0}`
+    pub name: String,
+    /// Branch the stash was made on; `None` if HEAD was detached.
+    pub branch: Option<String>,
+    pub message: String,
+}
+
+/// All stash entries, newest first. Stashes are shared by all worktrees.
+pub fn stash_list(repo: &Path) -> Result<Vec<StashEntry>> {
+    let output = run_git(
+        Some(repo),
+        &["log", "-g", "--format=%gd%x1f%gs", "refs/stash"],
+    )?;
+    if !output.status.success() {
+        // No refs/stash: nothing stashed
+        return Ok(Vec::new());
+    }
+    Ok(output
+        .stdout
+        .lines()
+        .filter_map(|line| line.split_once('\x1f'))
+        .map(|(name, message)| StashEntry {
+            name: name.to_string(),
+            branch: stash_branch(message).map(str::to_string),
+            message: message.to_string(),
+        })
+        .collect())
+}
+
+/// Number of stash entries per branch, from the branch git records in each
+/// stash message.
+pub fn stash_counts(repo: &Path) -> Result<HashMap<String, usize>> {
+    let mut counts = HashMap::new();
+    for branch in stash_list(repo)?.into_iter().filter_map(|s| s.branch) {
+        *counts.entry(branch).or_insert(0) += 1;
+    }
+    Ok(counts)
+}
+
+/// Branch of a stash message: `WIP on <branch>: ...` or `On <branch>: ...`.
+fn stash_branch(message: &str) -> Option<&str> {
+    let rest = message
+        .strip_prefix("WIP on ")
+        .or_else(|| message.strip_prefix("On "))?;
+    let (branch, _) = rest.split_once(':')?;
+    (branch != "(no branch)").then_some(branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commit_line() {
+        let (sha, info) = parse_commit_line("abc\x1f1700000000\x1fAda\x1ffix: a\x1fb").unwrap();
+        assert_eq!(sha, "abc");
+        assert_eq!(info.time, 1_700_000_000);
+        assert_eq!(info.author, "Ada");
+        assert_eq!(info.subject, "fix: a\x1fb");
+    }
+
+    #[test]
+    fn parses_log_line() {
+        assert_eq!(
+            parse_log_line("abc1234\x1f1700000000\x1fAdd search"),
+            Some(LogLine {
+                short_sha: "abc1234".into(),
+                subject: "Add search".into(),
+                time: 1_700_000_000,
+            })
+        );
+        assert_eq!(parse_log_line("garbage"), None);
+    }
+
+    #[test]
+    fn relative_ages() {
+        assert_eq!(relative_age(100, 130), "just now");
+        assert_eq!(relative_age(0, 3 * 3_600 + 5), "3h ago");
+        assert_eq!(relative_age(0, 10 * 86_400), "1w ago");
+        assert_eq!(relative_age(0, 400 * 86_400), "1y ago");
+        // clock skew
+        assert_eq!(relative_age(200, 100), "just now");
+    }
+
+    #[test]
+    fn stash_branch_from_message() {
+        assert_eq!(stash_branch("WIP on feat/x: abc123 msg"), Some("feat/x"));
+        assert_eq!(stash_branch("On main: before rebase"), Some("main"));
+        assert_eq!(stash_branch("WIP on (no branch): abc123 msg"), None);
+        assert_eq!(stash_branch("autostash"), None);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod executor;
+pub mod log;
 pub mod repo;
 pub mod status;
 pub mod worktree;
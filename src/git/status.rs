@@ -1,7 +1,136 @@
 use std::path::Path;
+use std::time::Duration;
 
-use crate::errors::Result;
-use crate::git::executor::run_git_ok;
+use serde::Serialize;
+
+use crate::config::UntrackedFiles;
+use crate::errors::{GrovError, Result};
+use crate::git::executor::{run_git_ok, run_git_with_timeout};
+
+/// Dirty state and upstream tracking of a worktree.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StatusSummary {
+    pub dirty: bool,
+    pub changes: ChangeCounts,
+    /// Configured upstream, even when it no longer exists on the remote.
+    pub upstream: Option<String>,
+    /// Commits ahead of and behind the upstream, if it exists.
+    pub ahead_behind: Option<(u32, u32)>,
+}
+
+/// Number of changed paths by kind; a path both staged and modified counts
+/// as both.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ChangeCounts {
+    pub staged: u32,
+    pub modified: u32,
+    pub untracked: u32,
+    pub conflicted: u32,
+}
+
+/// Read dirty state and ahead/behind with a single
+/// `git status --porcelain=v2 --branch` call, giving up after `timeout`.
+pub fn summary(
+    worktree_path: &Path,
+    untracked: UntrackedFiles,
+    timeout: Duration,
+) -> Result<StatusSummary> {
+    let path_str = worktree_path.to_string_lossy();
+    let untracked = format!("--untracked-files={}", untracked.as_str());
+    let args = [
+        "-C",
+        &path_str,
+        "status",
+        "--porcelain=v2",
+        "--branch",
+        &untracked,
+    ];
+    let output = run_git_with_timeout(None, &args, timeout)?;
+    if !output.status.success() {
+        return Err(GrovError::GitCommandFailed(output.stderr));
+    }
+    Ok(parse_status_v2(&output.stdout))
+}
+
+fn parse_status_v2(output: &str) -> StatusSummary {
+    let mut summary = StatusSummary::default();
+    for line in output.lines() {
+        if let Some(upstream) = line.strip_prefix("# branch.upstream ") {
+            summary.upstream = Some(upstream.to_string());
+        } else if let Some(ab) = line.strip_prefix("# branch.ab ") {
+            // `+<ahead> -<behind>`
+            let mut counts = ab
+                .split(' ')
+                .map(|n| n.trim_start_matches(['+', '-']).parse().unwrap_or(0));
+            summary.ahead_behind = counts.next().zip(counts.next());
+        } else if !line.starts_with('#') && !line.is_empty() {
+            summary.dirty = true;
+            count_change(&mut summary.changes, line);
+        }
+    }
+    summary
+}
+
+/// Changed paths in `git status --short` form (`XY path`), giving up after
+/// `timeout`.
+pub fn short_status(
+    worktree_path: &Path,
+    untracked: UntrackedFiles,
+    timeout: Duration,
+) -> Result<Vec<String>> {
+    let path_str = worktree_path.to_string_lossy();
+    let untracked = format!("--untracked-files={}", untracked.as_str());
+    // v2 rather than --short: output is trimmed, and short lines can start with a space
+    let output = run_git_with_timeout(
+        None,
+        &["-C", &path_str, "status", "--porcelain=v2", &untracked],
+        timeout,
+    )?;
+    if !output.status.success() {
+        return Err(GrovError::GitCommandFailed(output.stderr));
+    }
+    Ok(output.stdout.lines().filter_map(short_line).collect())
+}
+
+/// Turn a porcelain v2 entry into its `git status --short` line.
+fn short_line(line: &str) -> Option<String> {
+    // Number of space-separated fields before the path
+    let (xy, fields) = match line.split_once(' ')? {
+        ("1", rest) => (rest.get(..2)?, 8),
+        ("2", rest) => (rest.get(..2)?, 9),
+        ("u", rest) => (rest.get(..2)?, 10),
+        ("?", path) => return Some(format!("?? {path}")),
+        ("!", path) => return Some(format!("!! {path}")),
+        _ => return None,
+    };
+    let path = line.splitn(fields + 1, ' ').nth(fields)?;
+    let xy = xy.replace('.', " ");
+    // Renames are `new<TAB>old`; short format shows `old -> new`
+    Some(match path.split_once('\t') {
+        Some((new, old)) => format!("{xy} {old} -> {new}"),
+        None => format!("{xy} {path}"),
+    })
+}
+
+/// Count one porcelain v2 entry: `1 XY ...` and `2 XY ...` are changed
+/// and renamed paths, `u ...` unmerged, `? path` untracked.
+fn count_change(changes: &mut ChangeCounts, line: &str) {
+    let mut fields = line.splitn(3, ' ');
+    match (fields.next(), fields.next()) {
+        (Some("1" | "2"), Some(xy)) => {
+            let mut xy = xy.chars();
+            if xy.next().is_some_and(|x| x != '.') {
+                changes.staged += 1;
+            }
+            if xy.next().is_some_and(|y| y != '.') {
+                changes.modified += 1;
+            }
+        }
+        (Some("u"), _) => changes.conflicted += 1,
+        (Some("?"), _) => changes.untracked += 1,
+        _ => {}
+    }
+}
 
 /// Check if a worktree has uncommitted changes.
 pub fn is_dirty(worktree_path: &Path) -> Result<bool> {
@@ -10,10 +139,11 @@ pub fn is_dirty(worktree_path: &Path) -> Result<bool> {
     Ok(!output.is_empty())
 }
 
-/// Get ahead/behind counts relative to upstream.
-/// Returns `None` if no upstream is configured.
-pub fn ahead_behind(worktree_path: &Path) -> Result<Option<(u32, u32)>> {
+/// Get ahead/behind counts of HEAD relative to `target`, e.g. `@{upstream}`
+/// or `origin/main`. Returns `None` if `target` does not resolve.
+pub fn ahead_behind(worktree_path: &Path, target: &str) -> Result<Option<(u32, u32)>> {
     let path_str = worktree_path.to_string_lossy();
+    let range = format!("HEAD...{target}");
     let output = run_git_ok(
         None,
         &[
@@ -22,21 +152,131 @@ pub fn ahead_behind(worktree_path: &Path) -> Result<Option<(u32, u32)>> {
             "rev-list",
             "--left-right",
             "--count",
-            "HEAD...@{upstream}",
+            &range,
         ],
     );
+    // An error usually means no upstream is configured
+    Ok(output.ok().and_then(|text| parse_left_right(&text)))
+}
 
-    match output {
-        Ok(text) => {
-            let parts: Vec<&str> = text.split('\t').collect();
-            if parts.len() == 2 {
-                let ahead = parts[0].parse().unwrap_or(0);
-                let behind = parts[1].parse().unwrap_or(0);
-                Ok(Some((ahead, behind)))
-            } else {
-                Ok(None)
+/// Get ahead/behind counts of the ref `tip` relative to `target`, both
+/// resolved in the bare repository. Returns `None` if either does not resolve.
+pub fn ref_ahead_behind(repo: &Path, tip: &str, target: &str) -> Result<Option<(u32, u32)>> {
+    let range = format!("{tip}...{target}");
+    let output = run_git_ok(Some(repo), &["rev-list", "--left-right", "--count", &range]);
+    Ok(output.ok().and_then(|text| parse_left_right(&text)))
+}
+
+/// Parse `rev-list --left-right --count` output, `<left>\t<right>`.
+fn parse_left_right(text: &str) -> Option<(u32, u32)> {
+    let (ahead, behind) = text.split_once('\t')?;
+    Some((ahead.parse().unwrap_or(0), behind.parse().unwrap_or(0)))
+}
+
+/// Get the short name of the upstream branch (e.g. `origin/main`).
+/// Returns `None` if no upstream is configured.
+pub fn upstream(worktree_path: &Path) -> Result<Option<String>> {
+    let path_str = worktree_path.to_string_lossy();
+    let output = run_git_ok(
+        None,
+        &[
+            "-C",
+            &path_str,
+            "rev-parse",
+            "--abbrev-ref",
+            "--symbolic-full-name",
+            "@{upstream}",
+        ],
+    );
+    Ok(output.ok().filter(|name| !name.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_clean_tracking_branch() {
+        let output = "\
+# branch.oid 1234567890abcdef
+# branch.head feature/x
+# branch.upstream origin/feature/x
+# branch.ab +2 -3";
+        assert_eq!(
+            parse_status_v2(output),
+            StatusSummary {
+                dirty: false,
+                changes: ChangeCounts::default(),
+                upstream: Some("origin/feature/x".into()),
+                ahead_behind: Some((2, 3)),
             }
-        }
-        Err(_) => Ok(None), // No upstream configured
+        );
+    }
+
+    #[test]
+    fn parses_dirty_branch_with_gone_upstream() {
+        let output = "\
+# branch.oid 1234567890abcdef
+# branch.head feature/x
+# branch.upstream origin/feature/x
+1 .M N... 100644 100644 100644 abc abc README.md
+? scratch.txt";
+        let summary = parse_status_v2(output);
+        assert!(summary.dirty);
+        assert_eq!(
+            summary.changes,
+            ChangeCounts {
+                modified: 1,
+                untracked: 1,
+                ..ChangeCounts::default()
+            }
+        );
+        assert_eq!(summary.upstream.as_deref(), Some("origin/feature/x"));
+        assert_eq!(summary.ahead_behind, None);
+    }
+
+    #[test]
+    fn counts_staged_renamed_and_conflicted() {
+        let output = "\
+1 M. N... 100644 100644 100644 abc abc src/a.rs
+1 MM N... 100644 100644 100644 abc abc src/b.rs
+2 R. N... 100644 100644 100644 abc abc R100 new.rs\told.rs
+u UU N... 100644 100644 100644 100644 abc abc abc c.rs";
+        assert_eq!(
+            parse_status_v2(output).changes,
+            ChangeCounts {
+                staged: 3,
+                modified: 1,
+                untracked: 0,
+                conflicted: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn converts_v2_entries_to_short_lines() {
+        let lines: Vec<String> = "\
+1 .M N... 100644 100644 100644 abc abc src/my file.rs
+2 R. N... 100644 100644 100644 abc abc R100 new.rs\told.rs
+u UU N... 100644 100644 100644 100644 abc abc abc c.rs
+? scratch.txt"
+            .lines()
+            .filter_map(short_line)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                " M src/my file.rs",
+                "R  old.rs -> new.rs",
+                "UU c.rs",
+                "?? scratch.txt"
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_branch_without_upstream() {
+        let output = "# branch.oid 1234567890abcdef\n# branch.head main";
+        assert_eq!(parse_status_v2(output), StatusSummary::default());
     }
 }
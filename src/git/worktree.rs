@@ -1,26 +1,41 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
+
 use crate::errors::Result;
 use crate::git::executor::{run_git, run_git_ok};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WorktreeInfo {
     pub path: PathBuf,
     pub head: String,
     pub branch: Option<String>,
     pub is_bare: bool,
+    /// Lock reason, empty when locked without one; `None` when unlocked.
+    pub locked: Option<String>,
+    /// Why git considers the worktree prunable (usually a missing directory).
+    pub prunable: Option<String>,
 }
 
-/// Parse `git worktree list --porcelain` output into structured data.
+/// List worktrees via `git worktree list --porcelain`.
 pub fn list_worktrees(repo: &Path) -> Result<Vec<WorktreeInfo>> {
     let output = run_git_ok(Some(repo), &["worktree", "list", "--porcelain"])?;
+    Ok(parse_porcelain(&output))
+}
+
+/// Parse `git worktree list --porcelain` output into structured data.
+fn parse_porcelain(output: &str) -> Vec<WorktreeInfo> {
     let mut worktrees = Vec::new();
     let mut path = None;
     let mut head = None;
     let mut branch = None;
     let mut is_bare = false;
+    let mut locked = None;
+    let mut prunable = None;
 
-    for line in output.lines() {
+    // A trailing empty line flushes the last entry even without a final newline
+    for line in output.lines().chain([""]) {
         if let Some(p) = line.strip_prefix("worktree ") {
             path = Some(PathBuf::from(p));
         } else if let Some(h) = line.strip_prefix("HEAD ") {
@@ -31,6 +46,14 @@ pub fn list_worktrees(repo: &Path) -> Result<Vec<WorktreeInfo>> {
             branch = Some(b.to_string());
         } else if line == "bare" {
             is_bare = true;
+        } else if line == "locked" {
+            locked = Some(String::new());
+        } else if let Some(reason) = line.strip_prefix("locked ") {
+            locked = Some(reason.to_string());
+        } else if line == "prunable" {
+            prunable = Some(String::new());
+        } else if let Some(reason) = line.strip_prefix("prunable ") {
+            prunable = Some(reason.to_string());
         } else if line.is_empty() {
             if let (Some(p), Some(h)) = (path.take(), head.take()) {
                 worktrees.push(WorktreeInfo {
@@ -38,23 +61,18 @@ pub fn list_worktrees(repo: &Path) -> Result<Vec<WorktreeInfo>> {
                     head: h,
                     branch: branch.take(),
                     is_bare,
+                    locked: locked.take(),
+                    prunable: prunable.take(),
                 });
             }
+            branch = None;
             is_bare = false;
+            locked = None;
+            prunable = None;
         }
     }
 
-    // Handle last entry (no trailing newline)
-    if let (Some(p), Some(h)) = (path, head) {
-        worktrees.push(WorktreeInfo {
-            path: p,
-            head: h,
-            branch: branch.take(),
-            is_bare,
-        });
-    }
-
-    Ok(worktrees)
+    worktrees
 }
 
 /// Create a new worktree.
@@ -91,6 +109,50 @@ pub fn remove_worktree(repo: &Path, worktree_path: &Path, force: bool) -> Result
     Ok(())
 }
 
+/// Move a worktree to a new directory.
+pub fn move_worktree(repo: &Path, from: &Path, to: &Path) -> Result<()> {
+    let from_str = from.to_string_lossy();
+    let to_str = to.to_string_lossy();
+    run_git_ok(Some(repo), &["worktree", "move", &from_str, &to_str])?;
+    Ok(())
+}
+
+/// Rename a local branch, carrying its config (including upstream) along.
+pub fn rename_branch(repo: &Path, old: &str, new: &str) -> Result<()> {
+    run_git_ok(Some(repo), &["branch", "-m", old, new])?;
+    Ok(())
+}
+
+/// Lock a worktree so git refuses to move, remove or prune it.
+pub fn lock_worktree(repo: &Path, worktree_path: &Path, reason: Option<&str>) -> Result<()> {
+    let path_str = worktree_path.to_string_lossy();
+    let mut args = vec!["worktree", "lock"];
+    if let Some(reason) = reason {
+        args.extend(["--reason", reason]);
+    }
+    args.push(&path_str);
+    run_git_ok(Some(repo), &args)?;
+    Ok(())
+}
+
+pub fn unlock_worktree(repo: &Path, worktree_path: &Path) -> Result<()> {
+    let path_str = worktree_path.to_string_lossy();
+    run_git_ok(Some(repo), &["worktree", "unlock", &path_str])?;
+    Ok(())
+}
+
+/// Point `branch` at a new upstream, or remove its upstream with `None`.
+pub fn set_upstream(repo: &Path, branch: &str, upstream: Option<&str>) -> Result<()> {
+    match upstream {
+        Some(upstream) => {
+            let flag = format!("--set-upstream-to={upstream}");
+            run_git_ok(Some(repo), &["branch", &flag, branch])?
+        }
+        None => run_git_ok(Some(repo), &["branch", "--unset-upstream", branch])?,
+    };
+    Ok(())
+}
+
 /// Check if a local branch exists.
 pub fn branch_exists_local(repo: &Path, name: &str) -> bool {
     let refname = format!("refs/heads/{name}");
@@ -117,11 +179,11 @@ pub fn matches_branch_name(worktree: &WorktreeInfo, name: &str) -> bool {
     worktree.branch.as_deref() == Some(name)
 }
 
+/// Match a worktree by directory name, or by a trailing part of its path
+/// (`trees/feature-x`) for worktrees created from a nested path template.
 pub fn matches_dir_name(worktree: &WorktreeInfo, name: &str) -> bool {
-    worktree
-        .path
-        .file_name()
-        .is_some_and(|dir_name| dir_name.to_string_lossy() == name)
+    let name = Path::new(name);
+    !name.is_absolute() && name.components().next().is_some() && worktree.path.ends_with(name)
 }
 
 pub fn worktree_dir_name(worktree: &WorktreeInfo) -> String {
@@ -132,60 +194,220 @@ pub fn worktree_dir_name(worktree: &WorktreeInfo) -> String {
         .unwrap_or_default()
 }
 
-/// List available branches that don't already have a worktree.
-///
-/// Collects local and remote branches, deduplicates (local takes precedence),
-/// strips `origin/` prefix from remote branches, excludes `HEAD`, and filters
-/// out branches that already have a worktree.
-pub fn available_branches(repo: &Path, worktrees: &[WorktreeInfo]) -> Result<Vec<String>> {
-    use std::collections::BTreeSet;
+/// A branch that can be checked out into a new worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchInfo {
+    pub name: String,
+    /// Exists under `refs/heads/`.
+    pub local: bool,
+    /// Exists under `refs/remotes/origin/`.
+    pub remote: bool,
+    /// Committer date of the newer of the local and remote tips, as a unix timestamp.
+    pub time: i64,
+    /// Author of that tip.
+    pub author: String,
+    /// Upstream of the local branch, e.g. `origin/main`.
+    pub upstream: Option<String>,
+}
 
+/// List available branches that don't already have a worktree, most
+/// recently committed first.
+///
+/// Merges local and remote (`origin/`) branches by name, excludes `HEAD`,
+/// and filters out branches that already have a worktree.
+pub fn available_branches(repo: &Path, worktrees: &[WorktreeInfo]) -> Result<Vec<BranchInfo>> {
     let worktree_branches: std::collections::HashSet<&str> = worktrees
         .iter()
         .filter_map(|wt| wt.branch.as_deref())
         .collect();
 
-    let mut branches = BTreeSet::new();
-
-    // Local branches: git for-each-ref --format='%(refname:short)' refs/heads/
-    let local_output = run_git_ok(
+    let output = run_git_ok(
         Some(repo),
-        &["for-each-ref", "--format=%(refname:short)", "refs/heads/"],
+        &[
+            "for-each-ref",
+            "--format=%(refname)%1f%(committerdate:unix)%1f%(authorname)%1f%(upstream:short)",
+            "refs/heads/",
+            "refs/remotes/origin/",
+        ],
     )?;
-    for line in local_output.lines() {
-        let name = line.trim();
-        if !name.is_empty() {
-            branches.insert(name.to_string());
+
+    Ok(parse_branch_refs(&output)
+        .into_iter()
+        .filter(|b| !worktree_branches.contains(b.name.as_str()))
+        .collect())
+}
+
+/// Parse the `for-each-ref` output of [`available_branches`], merging local
+/// and remote refs of the same name and sorting newest first, then by name.
+fn parse_branch_refs(output: &str) -> Vec<BranchInfo> {
+    let mut branches: Vec<BranchInfo> = Vec::new();
+    // Index into `branches` by name, so local and remote refs merge in one pass
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for line in output.lines() {
+        let mut fields = line.split('\x1f');
+        let (Some(refname), Some(time), Some(author), upstream) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let (name, local) = if let Some(name) = refname.strip_prefix("refs/heads/") {
+            (name, true)
+        } else if let Some(name) = refname.strip_prefix("refs/remotes/origin/") {
+            (name, false)
+        } else {
+            continue;
+        };
+        if name.is_empty() || name == "HEAD" {
+            continue;
+        }
+        let time = time.parse().unwrap_or(0);
+        let upstream = upstream
+            .filter(|u| local && !u.is_empty())
+            .map(str::to_string);
+
+        let i = *index.entry(name.to_string()).or_insert_with(|| {
+            branches.push(BranchInfo {
+                name: name.to_string(),
+                local: false,
+                remote: false,
+                time: i64::MIN,
+                author: String::new(),
+                upstream: None,
+            });
+            branches.len() - 1
+        });
+        let entry = &mut branches[i];
+        if local {
+            entry.local = true;
+            entry.upstream = upstream;
+        } else {
+            entry.remote = true;
+        }
+        if time > entry.time {
+            entry.time = time;
+            entry.author = author.to_string();
         }
     }
+    branches.sort_by(|a, b| b.time.cmp(&a.time).then_with(|| a.name.cmp(&b.name)));
+    branches
+}
 
-    // Remote branches: git for-each-ref --format='%(refname:short)' refs/remotes/origin/
-    let remote_output = run_git_ok(
+/// List local branches that are fully merged into `target`.
+pub fn merged_branches(repo: &Path, target: &str) -> Result<Vec<String>> {
+    let merged = format!("--merged={target}");
+    let output = run_git_ok(
         Some(repo),
         &[
             "for-each-ref",
+            &merged,
             "--format=%(refname:short)",
-            "refs/remotes/origin/",
+            "refs/heads/",
         ],
     )?;
-    for line in remote_output.lines() {
-        let name = line.trim();
-        // Strip origin/ prefix, skip HEAD
-        if let Some(branch) = name.strip_prefix("origin/")
-            && branch != "HEAD"
-            && !branch.is_empty()
-        {
-            branches.insert(branch.to_string());
+    Ok(output
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Branches that `list --merged` and `remove --merged` treat as merged: those
+/// with work of their own merged into `default` (`origin/<default>` when it
+/// exists), without `default` itself.
+pub fn merged_into_default(repo: &Path, default: &str) -> Result<Vec<String>> {
+    let target = if branch_exists_remote(repo, default) {
+        format!("origin/{default}")
+    } else {
+        default.to_string()
+    };
+    let mut merged = merged_work_branches(repo, &target)?;
+    merged.retain(|b| b != default);
+    Ok(merged)
+}
+
+/// List local branches merged into `target` that carry work of their own.
+///
+/// `git branch --merged` also lists branches that were only cut from
+/// `target` and never committed to. Those are left out: a branch counts
+/// once it was pushed, or once a merge commit on `target` took it in. A
+/// branch still at the tip of `target` never counts. Branches that were
+/// fast-forwarded into `target` without ever being pushed look the same as
+/// fresh ones, so they are left out too.
+pub fn merged_work_branches(repo: &Path, target: &str) -> Result<Vec<String>> {
+    let target_tip = run_git_ok(
+        Some(repo),
+        &["rev-parse", "--verify", &format!("{target}^{{commit}}")],
+    )?;
+    let merged = format!("--merged={target}");
+    let output = run_git_ok(
+        Some(repo),
+        &[
+            "for-each-ref",
+            &merged,
+            "--format=%(refname:short)%09%(objectname)%09%(upstream)",
+            "refs/heads/",
+        ],
+    )?;
+
+    let mut branches = Vec::new();
+    for line in output.lines() {
+        let mut fields = line.split('\t');
+        let (Some(name), Some(tip), upstream) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        if name.is_empty() || tip == target_tip {
+            continue;
+        }
+        let pushed = upstream.is_some_and(|u| !u.is_empty()) || branch_exists_remote(repo, name);
+        if pushed || merged_by_merge_commit(repo, tip, target)? {
+            branches.push(name.to_string());
         }
     }
+    Ok(branches)
+}
 
-    // Filter out branches that already have a worktree
-    let result: Vec<String> = branches
-        .into_iter()
-        .filter(|b| !worktree_branches.contains(b.as_str()))
-        .collect();
+/// Whether a merge commit between `tip` and `target` took `tip` in as a
+/// side parent.
+fn merged_by_merge_commit(repo: &Path, tip: &str, target: &str) -> Result<bool> {
+    let range = format!("{tip}..{target}");
+    let output = run_git_ok(
+        Some(repo),
+        &[
+            "rev-list",
+            "--merges",
+            "--parents",
+            "--ancestry-path",
+            &range,
+        ],
+    )?;
+    Ok(has_side_parent(&output, tip))
+}
 
-    Ok(result)
+/// Whether any `rev-list --parents` line lists `commit` after the first parent.
+fn has_side_parent(rev_list: &str, commit: &str) -> bool {
+    rev_list
+        .lines()
+        .any(|line| line.split(' ').skip(2).any(|parent| parent == commit))
+}
+
+/// List local branches whose upstream branch no longer exists (`[gone]`).
+pub fn gone_branches(repo: &Path) -> Result<Vec<String>> {
+    let output = run_git_ok(
+        Some(repo),
+        &[
+            "for-each-ref",
+            "--format=%(refname:short)%09%(upstream:track)",
+            "refs/heads/",
+        ],
+    )?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .filter(|(_, track)| *track == "[gone]")
+        .map(|(name, _)| name.to_string())
+        .collect())
 }
 
 /// Try to delete a local branch safely (with `-d`).
@@ -201,6 +423,65 @@ pub fn safe_delete_branch(repo: &Path, name: &str) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn matches_dir_name_by_leaf_or_nested_suffix() {
+        let worktree = WorktreeInfo {
+            path: PathBuf::from("/project/trees/feature-x"),
+            head: "abc123".to_string(),
+            branch: Some("feature/x".to_string()),
+            is_bare: false,
+            locked: None,
+            prunable: None,
+        };
+        assert!(matches_dir_name(&worktree, "feature-x"));
+        assert!(matches_dir_name(&worktree, "trees/feature-x"));
+        assert!(!matches_dir_name(&worktree, "x"));
+        assert!(!matches_dir_name(&worktree, "other/feature-x"));
+        assert!(!matches_dir_name(&worktree, ""));
+    }
+
+    #[test]
+    fn side_parents_of_merge_commits() {
+        let rev_list = "m1 a1 b1\nm2 a2 b2 c2";
+        assert!(has_side_parent(rev_list, "b1"));
+        assert!(has_side_parent(rev_list, "c2"));
+        // First parents are the target's own line of history
+        assert!(!has_side_parent(rev_list, "a1"));
+        assert!(!has_side_parent(rev_list, "m1"));
+        assert!(!has_side_parent("", "b1"));
+    }
+
+    #[test]
+    fn parse_branch_refs_merges_and_sorts_by_date() {
+        let output = [
+            "refs/heads/main\x1f300\x1fAda\x1forigin/main",
+            "refs/heads/wip\x1f500\x1fAda\x1f",
+            "refs/remotes/origin/HEAD\x1f400\x1fBob\x1f",
+            "refs/remotes/origin/main\x1f400\x1fBob\x1f",
+            "refs/remotes/origin/review\x1f100\x1fCy\x1f",
+            "refs/remotes/origin/also-old\x1f100\x1fCy\x1f",
+        ]
+        .join("\n");
+        let branches = parse_branch_refs(&output);
+        let names: Vec<&str> = branches.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["wip", "main", "also-old", "review"]);
+
+        assert_eq!(
+            branches[1],
+            BranchInfo {
+                name: "main".to_string(),
+                local: true,
+                remote: true,
+                time: 400,
+                author: "Bob".to_string(),
+                upstream: Some("origin/main".to_string()),
+            }
+        );
+        assert!(branches[0].local && !branches[0].remote);
+        assert_eq!(branches[0].upstream, None);
+        assert!(!branches[3].local && branches[3].remote);
+    }
+
     #[test]
     fn parse_porcelain_output() {
         let output = "\
@@ -215,52 +496,33 @@ branch refs/heads/main
 worktree /repos/project.git/trees/feature
 HEAD 789abc
 branch refs/heads/feature/login
-";
-        // We test the parsing logic indirectly; the function calls git directly
-        // so we test the struct construction logic here
-        let mut worktrees = Vec::new();
-        let mut path = None;
-        let mut head = None;
-        let mut branch = None;
-        let mut is_bare = false;
-
-        for line in output.lines() {
-            if let Some(p) = line.strip_prefix("worktree ") {
-                path = Some(PathBuf::from(p));
-            } else if let Some(h) = line.strip_prefix("HEAD ") {
-                head = Some(h.to_string());
-            } else if let Some(b) = line.strip_prefix("branch ") {
-                let b = b.strip_prefix("refs/heads/").unwrap_or(b);
-                branch = Some(b.to_string());
-            } else if line == "bare" {
-                is_bare = true;
-            } else if line.is_empty() {
-                if let (Some(p), Some(h)) = (path.take(), head.take()) {
-                    worktrees.push(WorktreeInfo {
-                        path: p,
-                        head: h,
-                        branch: branch.take(),
-                        is_bare,
-                    });
-                }
-                is_bare = false;
-            }
-        }
+locked on a usb drive
 
-        // Handle last entry (no trailing blank line)
-        if let (Some(p), Some(h)) = (path, head) {
-            worktrees.push(WorktreeInfo {
-                path: p,
-                head: h,
-                branch: branch.take(),
-                is_bare,
-            });
-        }
+worktree /repos/project.git/trees/detached
+HEAD 012def
+detached
+locked
+
+worktree /repos/project.git/trees/gone
+HEAD 345678
+branch refs/heads/gone
+prunable gitdir file points to non-existent location
+";
+        let worktrees = parse_porcelain(output);
 
-        assert_eq!(worktrees.len(), 3);
+        assert_eq!(worktrees.len(), 5);
         assert!(worktrees[0].is_bare);
         assert_eq!(worktrees[0].branch, None);
         assert_eq!(worktrees[1].branch.as_deref(), Some("main"));
+        assert_eq!(worktrees[1].locked, None);
         assert_eq!(worktrees[2].branch.as_deref(), Some("feature/login"));
+        assert_eq!(worktrees[2].locked.as_deref(), Some("on a usb drive"));
+        assert_eq!(worktrees[3].branch, None);
+        assert_eq!(worktrees[3].locked.as_deref(), Some(""));
+        assert_eq!(worktrees[3].prunable, None);
+        assert_eq!(
+            worktrees[4].prunable.as_deref(),
+            Some("gitdir file points to non-existent location")
+        );
     }
 }
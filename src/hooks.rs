@@ -0,0 +1,88 @@
+use std::path::Path;
+use std::process::Command;
+
+use console::style;
+
+use crate::config::HooksConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PostAdd,
+    PostInit,
+    PreRemove,
+    PostRemove,
+}
+
+impl Hook {
+    pub const ALL: [Hook; 4] = [
+        Hook::PostAdd,
+        Hook::PostInit,
+        Hook::PreRemove,
+        Hook::PostRemove,
+    ];
+
+    /// The key used in the `[hooks]` section of `.grov.toml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Hook::PostAdd => "post_add",
+            Hook::PostInit => "post_init",
+            Hook::PreRemove => "pre_remove",
+            Hook::PostRemove => "post_remove",
+        }
+    }
+
+    pub fn commands(self, hooks: &HooksConfig) -> &[String] {
+        match self {
+            Hook::PostAdd => &hooks.post_add,
+            Hook::PostInit => &hooks.post_init,
+            Hook::PreRemove => &hooks.pre_remove,
+            Hook::PostRemove => &hooks.post_remove,
+        }
+    }
+}
+
+/// The worktree a hook runs for, exposed to hook commands as environment variables.
+pub struct HookContext<'a> {
+    pub branch: Option<&'a str>,
+    pub worktree: &'a Path,
+    pub bare_repo: &'a Path,
+}
+
+/// Run the configured commands for `hook` through `sh -c`, in `cwd`.
+///
+/// Commands run in order and stop at the first failure, which is returned
+/// as an error. Hook commands see `GROV_HOOK`, `GROV_BRANCH`, `GROV_WORKTREE`
+/// and `GROV_BARE_REPO`.
+pub fn run_hook(
+    hooks: &HooksConfig,
+    hook: Hook,
+    ctx: &HookContext,
+    cwd: &Path,
+) -> anyhow::Result<()> {
+    for command in hook.commands(hooks) {
+        eprintln!(
+            "{}",
+            style(format!("  \u{2192} {} hook: {command}", hook.name())).dim()
+        );
+        let status = Command::new("sh")
+            .args(["-c", command])
+            .current_dir(cwd)
+            .env("GROV_HOOK", hook.name())
+            .env("GROV_BRANCH", ctx.branch.unwrap_or_default())
+            .env("GROV_WORKTREE", ctx.worktree)
+            .env("GROV_BARE_REPO", ctx.bare_repo)
+            .status()?;
+        if !status.success() {
+            anyhow::bail!("{} hook `{command}` failed ({status})", hook.name());
+        }
+    }
+    Ok(())
+}
+
+/// Run a post-event hook, downgrading failures to a warning since the
+/// event itself already happened.
+pub fn run_post_hook(hooks: &HooksConfig, hook: Hook, ctx: &HookContext, cwd: &Path) {
+    if let Err(err) = run_hook(hooks, hook, ctx, cwd) {
+        eprintln!("{} {err:#}", style("warning:").yellow().bold());
+    }
+}
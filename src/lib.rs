@@ -1,42 +1,173 @@
+pub mod branch;
 pub mod cli;
 pub mod commands;
 pub mod config;
 pub mod errors;
+pub mod files;
 pub mod git;
+pub mod hooks;
 pub mod paths;
 pub mod tui;
 
 use anyhow::Context;
 use clap::Parser;
 
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, ConfigCommand};
 
 pub fn run() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Init { path } => {
-            commands::init::execute(path.as_deref()).context("init failed")?;
+        Commands::Init {
+            url,
+            name,
+            prefix,
+            branch,
+            path,
+            no_hooks,
+        } => {
+            commands::init::execute(
+                url.as_deref(),
+                name.as_deref(),
+                prefix.as_deref(),
+                branch.as_deref(),
+                path.as_deref(),
+                no_hooks,
+            )
+            .context("init failed")?;
+        }
+        Commands::Add {
+            branch,
+            base,
+            path,
+            copy_from,
+            no_hooks,
+        } => {
+            commands::add::execute(
+                branch.as_deref(),
+                base.as_deref(),
+                path.as_deref(),
+                copy_from.as_deref(),
+                no_hooks,
+            )
+            .context("add failed")?;
+        }
+        Commands::List {
+            compact,
+            format,
+            columns,
+            long,
+            sort,
+            filters,
+        } => {
+            commands::list::execute(compact, format, &columns, long, sort, &filters)
+                .context("list failed")?;
         }
-        Commands::Add { branch, base, path } => {
-            commands::add::execute(branch.as_deref(), base.as_deref(), path.as_deref())
-                .context("add failed")?;
+        Commands::Status { format } => {
+            commands::status::execute(format).context("status failed")?;
         }
-        Commands::List { compact } => {
-            commands::list::execute(compact).context("list failed")?;
+        Commands::Ui => {
+            commands::ui::execute().context("ui failed")?;
         }
         Commands::Remove {
             name,
             match_mode,
             delete_branch,
             force,
+            merged,
+            yes,
+            allow_protected,
+            no_hooks,
         } => {
-            commands::remove::execute(name.as_deref(), match_mode, delete_branch, force)
+            if merged {
+                commands::remove::execute_merged(force, yes, allow_protected, no_hooks)
+                    .context("remove failed")?;
+            } else {
+                commands::remove::execute(
+                    name.as_deref(),
+                    match_mode,
+                    delete_branch,
+                    force,
+                    allow_protected,
+                    no_hooks,
+                )
                 .context("remove failed")?;
+            }
+        }
+        Commands::Move {
+            name,
+            new_branch,
+            match_mode,
+            path,
+            set_upstream,
+        } => {
+            commands::mv::execute(
+                &name,
+                &new_branch,
+                match_mode,
+                path.as_deref(),
+                set_upstream,
+            )
+            .context("mv failed")?;
+        }
+        Commands::Lock {
+            name,
+            match_mode,
+            reason,
+        } => {
+            commands::lock::execute_lock(&name, match_mode, reason.as_deref())
+                .context("lock failed")?;
+        }
+        Commands::Unlock { name, match_mode } => {
+            commands::lock::execute_unlock(&name, match_mode).context("unlock failed")?;
+        }
+        Commands::Foreach {
+            dirty,
+            clean,
+            branch,
+            jobs,
+            command,
+        } => {
+            commands::foreach::execute(dirty, clean, branch.as_deref(), jobs.into(), &command)
+                .context("foreach failed")?;
+        }
+        Commands::Prune {
+            dry_run,
+            yes,
+            delete_dirs,
+        } => {
+            commands::prune::execute(dry_run, yes, delete_dirs).context("prune failed")?;
+        }
+        Commands::Switch { name, match_mode } => {
+            commands::switch::execute(&name, match_mode).context("switch failed")?;
+        }
+        Commands::ShellInit { shell } => {
+            commands::shell_init::execute(shell);
         }
         Commands::Completions { shell } => {
             commands::completions::execute(shell);
         }
+        Commands::Config { command } => match command {
+            ConfigCommand::Show { origin } => {
+                commands::config::execute_show(origin).context("config failed")?;
+            }
+            ConfigCommand::Get { key, global } => {
+                commands::config::execute_get(&key, global).context("config failed")?;
+            }
+            ConfigCommand::Set {
+                key,
+                values,
+                global,
+            } => {
+                commands::config::execute_set(&key, &values, global).context("config failed")?;
+            }
+            ConfigCommand::Unset { key, global } => {
+                commands::config::execute_unset(&key, global).context("config failed")?;
+            }
+            ConfigCommand::Validate { files } => {
+                commands::config::execute_validate(&files).context("config failed")?;
+            }
+        },
     }
 
     Ok(())
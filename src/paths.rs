@@ -1,4 +1,13 @@
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Context;
+use regex::Regex;
+
+use crate::config::WorktreeConfig;
+
+/// Ticket IDs like `ABC-123`, used for `{ticket}` when
+/// `worktree.ticket_pattern` is not set.
+pub const DEFAULT_TICKET_PATTERN: &str = r"[A-Z][A-Z0-9]+-\d+";
 
 /// Convert a branch name into a safe directory name.
 /// `/` → `-`, strip leading `.`, collapse consecutive `-`.
@@ -40,6 +49,91 @@ pub fn worktree_dir(bare_repo: &Path, branch: &str, prefix: &str) -> PathBuf {
     parent.join(dir_name)
 }
 
+/// Build the worktree path for `branch` from the project's naming settings.
+///
+/// Without a `path_template` this is [`worktree_dir`]. A template is a path
+/// relative to the project directory using `{prefix}`, `{branch}` (sanitized),
+/// `{branch_leaf}` (last `/` segment, sanitized) and `{ticket}` (taken from
+/// the branch name with `ticket_pattern`), e.g. `trees/{ticket}-{branch_leaf}`.
+pub fn worktree_path(
+    bare_repo: &Path,
+    branch: &str,
+    config: &WorktreeConfig,
+) -> anyhow::Result<PathBuf> {
+    if config.path_template.is_empty() {
+        return Ok(worktree_dir(bare_repo, branch, &config.prefix));
+    }
+
+    let rendered = render_template(&config.path_template, branch, config)?;
+    let relative = Path::new(&rendered);
+    let inside_project = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+        && relative
+            .components()
+            .any(|c| matches!(c, Component::Normal(_)));
+    if !inside_project {
+        anyhow::bail!(
+            "worktree.path_template renders to `{rendered}`, which is not inside the project directory"
+        );
+    }
+    if relative.components().next() == bare_repo.file_name().map(Component::Normal) {
+        anyhow::bail!("worktree.path_template renders to `{rendered}`, inside the bare repo");
+    }
+
+    let parent = bare_repo
+        .parent()
+        .expect("bare repo must have a parent dir");
+    Ok(parent.join(relative))
+}
+
+fn render_template(
+    template: &str,
+    branch: &str,
+    config: &WorktreeConfig,
+) -> anyhow::Result<String> {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = after
+            .find('}')
+            .with_context(|| format!("unclosed `{{` in worktree.path_template `{template}`"))?;
+        let value = match &after[..end] {
+            "prefix" => config.prefix.clone(),
+            "branch" => sanitize_branch_name(branch),
+            "branch_leaf" => sanitize_branch_name(branch.rsplit('/').next().unwrap_or(branch)),
+            "ticket" => ticket_id(branch, &config.ticket_pattern)?,
+            other => anyhow::bail!("unknown placeholder `{{{other}}}` in worktree.path_template"),
+        };
+        rendered.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// Extract the ticket ID from a branch name: the first capture group of
+/// `pattern`, or the whole match if it has none.
+pub fn ticket_id(branch: &str, pattern: &str) -> anyhow::Result<String> {
+    let pattern = if pattern.is_empty() {
+        DEFAULT_TICKET_PATTERN
+    } else {
+        pattern
+    };
+    let re = Regex::new(pattern)
+        .with_context(|| format!("invalid worktree.ticket_pattern `{pattern}`"))?;
+    let captures = re.captures(branch).with_context(|| {
+        format!("branch `{branch}` has no ticket ID matching `{pattern}` (needed for {{ticket}})")
+    })?;
+    let ticket = captures
+        .get(1)
+        .or_else(|| captures.get(0))
+        .expect("a match has group 0");
+    Ok(sanitize_branch_name(ticket.as_str()))
+}
+
 /// Extract the repository name from a URL, stripping `.git` suffix.
 pub fn repo_name_from_url(url: &str) -> String {
     let name = url
@@ -135,6 +229,58 @@ mod tests {
         );
     }
 
+    fn template(path_template: &str) -> WorktreeConfig {
+        WorktreeConfig {
+            prefix: "mp".to_string(),
+            path_template: path_template.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn worktree_path_without_template_uses_legacy_naming() {
+        let bare = Path::new("/repos/myproject/repo.git");
+        assert_eq!(
+            worktree_path(bare, "feature/login", &template("")).unwrap(),
+            PathBuf::from("/repos/myproject/mp_feature-login")
+        );
+    }
+
+    #[test]
+    fn worktree_path_renders_placeholders() {
+        let bare = Path::new("/repos/myproject/repo.git");
+        assert_eq!(
+            worktree_path(bare, "feature/login", &template("trees/{prefix}-{branch}")).unwrap(),
+            PathBuf::from("/repos/myproject/trees/mp-feature-login")
+        );
+        assert_eq!(
+            worktree_path(
+                bare,
+                "feat/ABC-123/login-page",
+                &template("{ticket}/{branch_leaf}")
+            )
+            .unwrap(),
+            PathBuf::from("/repos/myproject/ABC-123/login-page")
+        );
+    }
+
+    #[test]
+    fn worktree_path_rejects_paths_outside_project() {
+        let bare = Path::new("/repos/myproject/repo.git");
+        assert!(worktree_path(bare, "x", &template("../{branch}")).is_err());
+        assert!(worktree_path(bare, "x", &template("/tmp/{branch}")).is_err());
+        assert!(worktree_path(bare, "x", &template("repo.git/{branch}")).is_err());
+        assert!(worktree_path(bare, "x", &template("{nope}")).is_err());
+        assert!(worktree_path(bare, "x", &template("trees/{branch")).is_err());
+    }
+
+    #[test]
+    fn ticket_id_uses_capture_group_or_match() {
+        assert_eq!(ticket_id("fix/PROJ-42-crash", "").unwrap(), "PROJ-42");
+        assert_eq!(ticket_id("fix/gh-17-crash", r"gh-(\d+)").unwrap(), "17");
+        assert!(ticket_id("fix/crash", "").is_err());
+    }
+
     #[test]
     fn repo_name_from_https_url() {
         assert_eq!(
@@ -0,0 +1,101 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// Loads values on a single background thread, always working on the most
+/// recent request, so the UI never waits on git and fast scrolling does not
+/// pile up work.
+///
+/// The worker exits once the loader is dropped and its current load ends.
+pub struct Loader<K, V> {
+    requests: Sender<K>,
+    results: Receiver<(K, V)>,
+    /// The latest request, until its result arrives.
+    pending: Option<K>,
+}
+
+impl<K, V> Loader<K, V>
+where
+    K: Clone + PartialEq + Send + 'static,
+    V: Send + 'static,
+{
+    pub fn new(load: impl Fn(&K) -> V + Send + 'static) -> Self {
+        let (requests, queue) = mpsc::channel::<K>();
+        let (done, results) = mpsc::channel();
+        std::thread::spawn(move || {
+            while let Ok(mut key) = queue.recv() {
+                // Skip requests superseded while the previous load ran
+                while let Ok(newer) = queue.try_recv() {
+                    key = newer;
+                }
+                let value = load(&key);
+                if done.send((key, value)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            requests,
+            results,
+            pending: None,
+        }
+    }
+
+    /// Ask for `key`, unless it is already the latest request.
+    pub fn request(&mut self, key: K) {
+        if self.pending.as_ref() == Some(&key) {
+            return;
+        }
+        // The worker only stops once `self` is dropped
+        let _ = self.requests.send(key.clone());
+        self.pending = Some(key);
+    }
+
+    /// Whether the latest request is still loading.
+    pub fn is_loading(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Loads finished since the last call, oldest first.
+    pub fn finished(&mut self) -> Vec<(K, V)> {
+        let finished: Vec<(K, V)> = self.results.try_iter().collect();
+        if finished
+            .iter()
+            .any(|(key, _)| self.pending.as_ref() == Some(key))
+        {
+            self.pending = None;
+        }
+        finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn wait_for<K, V>(loader: &mut Loader<K, V>) -> Vec<(K, V)>
+    where
+        K: Clone + PartialEq + Send + 'static,
+        V: Send + 'static,
+    {
+        let start = Instant::now();
+        loop {
+            let finished = loader.finished();
+            if !finished.is_empty() || start.elapsed() > Duration::from_secs(5) {
+                return finished;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn loads_requests_and_skips_repeats() {
+        let mut loader = Loader::new(|n: &u32| n * 2);
+        loader.request(21);
+        loader.request(21);
+        assert_eq!(wait_for(&mut loader), vec![(21, 42)]);
+
+        // Pending cleared, so the same key can be loaded again
+        loader.request(21);
+        assert_eq!(wait_for(&mut loader), vec![(21, 42)]);
+    }
+}
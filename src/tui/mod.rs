@@ -1,4 +1,6 @@
+pub mod clipboard;
 pub mod confirm;
+pub mod loader;
 pub mod select_list;
 pub mod step_bar;
 pub mod terminal;
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Frame;
 use ratatui::layout::Rect;
@@ -12,6 +14,8 @@ pub enum SelectResult {
     Item(usize),
     /// User selected an extra option (index into the `extra_options` slice).
     Extra(usize),
+    /// User confirmed a multi-select list (indices into `items`, ascending).
+    Items(Vec<usize>),
 }
 
 pub enum Action {
@@ -27,6 +31,12 @@ pub struct SelectList {
     pub filter: String,
     pub filtered_indices: Vec<usize>,
     pub list_state: ListState,
+    pub multi: bool,
+    pub checked: BTreeSet<usize>,
+    /// Extra spans shown after each item, aligned in a column; not filtered on.
+    pub hints: Vec<Vec<Span<'static>>>,
+    /// Matched character positions of each filtered item, for highlighting.
+    match_positions: Vec<Vec<usize>>,
 }
 
 impl SelectList {
@@ -43,22 +53,42 @@ impl SelectList {
             filter: String::new(),
             filtered_indices,
             list_state,
+            multi: false,
+            checked: BTreeSet::new(),
+            hints: Vec::new(),
+            match_positions: Vec::new(),
         }
     }
 
+    /// Let the user check several items with Space; Enter returns them all.
+    pub fn with_multi_select(mut self, checked: impl IntoIterator<Item = usize>) -> Self {
+        self.multi = true;
+        self.checked = checked.into_iter().collect();
+        self
+    }
+
+    /// Show `hints[i]` after item `i`, e.g. dates or markers.
+    pub fn with_hints(mut self, hints: Vec<Vec<Span<'static>>>) -> Self {
+        self.hints = hints;
+        self
+    }
+
     fn total_visible(&self) -> usize {
         self.filtered_indices.len() + self.extra_options.len()
     }
 
+    /// Rank items by fuzzy score against the filter, best first. Ties keep
+    /// the original order; an empty filter shows everything unranked.
     fn update_filter(&mut self) {
-        let lower = self.filter.to_lowercase();
-        self.filtered_indices = self
+        let mut matches: Vec<(usize, FuzzyMatch)> = self
             .items
             .iter()
             .enumerate()
-            .filter(|(_, item)| item.to_lowercase().contains(&lower))
-            .map(|(i, _)| i)
+            .filter_map(|(i, item)| fuzzy_match(&self.filter, item).map(|m| (i, m)))
             .collect();
+        matches.sort_by_key(|(_, m)| std::cmp::Reverse(m.score));
+        (self.filtered_indices, self.match_positions) =
+            matches.into_iter().map(|(i, m)| (i, m.positions)).unzip();
 
         // Reset selection
         if self.total_visible() > 0 {
@@ -68,6 +98,43 @@ impl SelectList {
         }
     }
 
+    fn toggle_current(&mut self) {
+        let Some(&orig_idx) = self
+            .list_state
+            .selected()
+            .and_then(|sel| self.filtered_indices.get(sel))
+        else {
+            return;
+        };
+        if !self.checked.remove(&orig_idx) {
+            self.checked.insert(orig_idx);
+        }
+    }
+
+    /// Check every visible item, or uncheck them all if they already are.
+    /// Items hidden by the filter keep their state.
+    fn toggle_all_visible(&mut self) {
+        let all_checked = self
+            .filtered_indices
+            .iter()
+            .all(|i| self.checked.contains(i));
+        for &i in &self.filtered_indices {
+            if all_checked {
+                self.checked.remove(&i);
+            } else {
+                self.checked.insert(i);
+            }
+        }
+    }
+
+    /// Index into `items` of the highlighted row, if it is an item.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.list_state
+            .selected()
+            .and_then(|sel| self.filtered_indices.get(sel))
+            .copied()
+    }
+
     fn move_up(&mut self) {
         let total = self.total_visible();
         if total == 0 {
@@ -96,7 +163,21 @@ impl SelectList {
             if *modifiers == KeyModifiers::CONTROL && *code == KeyCode::Char('c') {
                 std::process::exit(130);
             }
+            if self.multi && *modifiers == KeyModifiers::CONTROL && *code == KeyCode::Char('a') {
+                self.toggle_all_visible();
+                return Action::Continue;
+            }
             match code {
+                KeyCode::Enter if self.multi => {
+                    return Action::Selected(SelectResult::Items(
+                        self.checked.iter().copied().collect(),
+                    ));
+                }
+                KeyCode::Char(' ') if self.multi => self.toggle_current(),
+                // Once a filter is typed, `a` is part of it; Ctrl+A still works
+                KeyCode::Char('a') if self.multi && self.filter.is_empty() => {
+                    self.toggle_all_visible()
+                }
                 KeyCode::Enter => {
                     if let Some(selected) = self.list_state.selected() {
                         let filtered_count = self.filtered_indices.len();
@@ -131,11 +212,18 @@ impl SelectList {
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         let mut y = area.y;
 
-        // Label
-        let label_line = Line::from(vec![
+        // Label, with the number of checked items in multi-select mode
+        let mut label_spans = vec![
             Span::styled("  ? ", theme::CYAN),
             Span::styled(&self.label, theme::CYAN),
-        ]);
+        ];
+        if self.multi {
+            label_spans.push(Span::styled(
+                format!("  ({} selected)", self.checked.len()),
+                theme::DIM,
+            ));
+        }
+        let label_line = Line::from(label_spans);
         frame.render_widget(
             Paragraph::new(label_line),
             Rect::new(area.x, y, area.width, 1),
@@ -157,9 +245,33 @@ impl SelectList {
         // List items
         let available_height = area.height.saturating_sub(y - area.y + 2) as usize; // reserve 2 for help
         let mut list_items: Vec<ListItem> = Vec::new();
+        let item_width = if self.hints.is_empty() {
+            0
+        } else {
+            self.items
+                .iter()
+                .map(|item| Span::raw(item).width())
+                .max()
+                .unwrap_or(0)
+        };
 
-        for &orig_idx in &self.filtered_indices {
-            list_items.push(ListItem::new(format!("    {}", self.items[orig_idx])));
+        for (row, &orig_idx) in self.filtered_indices.iter().enumerate() {
+            let mut spans = if !self.multi {
+                vec![Span::raw("    ")]
+            } else if self.checked.contains(&orig_idx) {
+                vec![Span::styled("    \u{25c9} ", theme::GREEN)] // ◉
+            } else {
+                vec![Span::raw("    \u{25cb} ")] // ○
+            };
+            let positions = self.match_positions.get(row).map_or(&[][..], Vec::as_slice);
+            let item = &self.items[orig_idx];
+            spans.extend(highlight_matches(item, positions));
+            if let Some(hint) = self.hints.get(orig_idx) {
+                let pad = item_width.saturating_sub(Span::raw(item).width());
+                spans.push(Span::raw(" ".repeat(pad + 2)));
+                spans.extend(hint.iter().cloned());
+            }
+            list_items.push(ListItem::new(Line::from(spans)));
         }
 
         // Separator + extra options
@@ -213,6 +325,135 @@ impl SelectList {
     }
 }
 
+/// Highlight positions in `text`, splitting it into runs of matched and
+/// unmatched characters.
+fn highlight_matches<'a>(text: &'a str, positions: &[usize]) -> Vec<Span<'a>> {
+    if positions.is_empty() {
+        return vec![Span::raw(text)];
+    }
+    let mut spans = Vec::new();
+    let mut run_start = 0;
+    let mut run_matched = false;
+    for (pos, (byte, _)) in text.char_indices().enumerate() {
+        let matched = positions.binary_search(&pos).is_ok();
+        if matched != run_matched && byte > run_start {
+            spans.push(run_span(&text[run_start..byte], run_matched));
+            run_start = byte;
+        }
+        run_matched = matched;
+    }
+    spans.push(run_span(&text[run_start..], run_matched));
+    spans
+}
+
+fn run_span(text: &str, matched: bool) -> Span<'_> {
+    if matched {
+        Span::styled(text, theme::MATCH)
+    } else {
+        Span::raw(text)
+    }
+}
+
+const SCORE_MATCH: i32 = 16;
+const BONUS_SEGMENT: i32 = 10;
+const BONUS_BOUNDARY: i32 = 8;
+const BONUS_CAMEL: i32 = 7;
+const BONUS_CONSECUTIVE: i32 = 8;
+/// The first pattern character's position bonus counts this many times.
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+const PENALTY_GAP_START: i32 = 3;
+const PENALTY_GAP_EXTENSION: i32 = 1;
+
+#[derive(Debug, PartialEq, Eq)]
+struct FuzzyMatch {
+    score: i32,
+    /// Character (not byte) positions in the candidate, ascending.
+    positions: Vec<usize>,
+}
+
+/// Bonus for a match at `index`, based on the character before it: the start
+/// of the text or of a path segment ranks highest, then word boundaries and
+/// camelCase humps.
+fn position_bonus(chars: &[char], index: usize) -> i32 {
+    let Some(&prev) = index.checked_sub(1).and_then(|i| chars.get(i)) else {
+        return BONUS_SEGMENT;
+    };
+    let current = chars[index];
+    if prev == '/' {
+        BONUS_SEGMENT
+    } else if matches!(prev, '-' | '_' | '.' | ':') || prev.is_whitespace() {
+        BONUS_BOUNDARY
+    } else if prev.is_lowercase() && current.is_uppercase() {
+        BONUS_CAMEL
+    } else {
+        0
+    }
+}
+
+/// Match `pattern` as a case-insensitive subsequence of `candidate`, picking
+/// the alignment with the best score. `None` if some character is missing.
+fn fuzzy_match(pattern: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    if pattern.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    let chars: Vec<char> = candidate.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let (m, n) = (pattern.len(), chars.len());
+    if m > n {
+        return None;
+    }
+
+    // best[i][j]: best score with pattern[..=i] matched and pattern[i] at j;
+    // from[i][j]: where pattern[i - 1] matched on that path
+    let mut best = vec![vec![None::<i32>; n]; m];
+    let mut from = vec![vec![0usize; n]; m];
+    for (i, &p) in pattern.iter().enumerate() {
+        // Best way to reach j with a gap after the previous match: (score, at)
+        let mut gapped: Option<(i32, usize)> = None;
+        for j in i..n {
+            if i > 0 && j >= 2 {
+                let extended = gapped.map(|(score, at)| (score - PENALTY_GAP_EXTENSION, at));
+                let opened = best[i - 1][j - 2].map(|score| (score - PENALTY_GAP_START, j - 2));
+                gapped = extended.into_iter().chain(opened).max_by_key(|&(s, _)| s);
+            }
+            if lower[j] != p {
+                continue;
+            }
+            let bonus = position_bonus(&chars, j);
+            if i == 0 {
+                best[i][j] = Some(SCORE_MATCH + bonus * BONUS_FIRST_CHAR_MULTIPLIER);
+                continue;
+            }
+            let bonus = SCORE_MATCH + bonus;
+            let adjacent = best[i - 1][j - 1].map(|score| (score + BONUS_CONSECUTIVE, j - 1));
+            // On a tie the adjacent match wins, as `max_by_key` keeps the last
+            if let Some((score, at)) = gapped.into_iter().chain(adjacent).max_by_key(|&(s, _)| s) {
+                best[i][j] = Some(score + bonus);
+                from[i][j] = at;
+            }
+        }
+    }
+
+    let (mut j, score) = best[m - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|s| (j, s)))
+        .max_by_key(|&(j, s)| (s, std::cmp::Reverse(j)))?;
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +485,143 @@ mod tests {
         assert_eq!(list.filtered_indices, vec![0]);
     }
 
+    #[test]
+    fn fuzzy_matches_subsequence() {
+        let m = fuzzy_match("flogin", "feature/login").unwrap();
+        assert_eq!(m.positions, vec![0, 8, 9, 10, 11, 12]);
+        assert!(fuzzy_match("flogni", "feature/login").is_none());
+        assert!(fuzzy_match("x", "").is_none());
+    }
+
+    #[test]
+    fn fuzzy_is_case_insensitive() {
+        let m = fuzzy_match("FL", "feature/Login").unwrap();
+        assert_eq!(m.positions, vec![0, 8]);
+    }
+
+    #[test]
+    fn fuzzy_prefers_segment_and_word_starts() {
+        // The `l` of "login" starts a path segment, the one in "release" does not
+        let m = fuzzy_match("rl", "release/login").unwrap();
+        assert_eq!(m.positions, vec![0, 8]);
+        let m = fuzzy_match("lb", "local-branch").unwrap();
+        assert_eq!(m.positions, vec![0, 6]);
+        let m = fuzzy_match("gB", "getBranch").unwrap();
+        assert_eq!(m.positions, vec![0, 3]);
+    }
+
+    #[test]
+    fn fuzzy_prefers_consecutive_matches() {
+        let m = fuzzy_match("log", "l-o-g/login").unwrap();
+        assert_eq!(m.positions, vec![6, 7, 8]);
+        let consecutive = fuzzy_match("ab", "xabx").unwrap().score;
+        let gapped = fuzzy_match("ab", "xaxbx").unwrap().score;
+        assert!(consecutive > gapped);
+    }
+
+    #[test]
+    fn filter_ranks_by_score() {
+        let mut list = SelectList::new(
+            "test",
+            vec![
+                "backlog".into(),
+                "blxoxg".into(),
+                "feature/login".into(),
+                "main".into(),
+            ],
+            vec![],
+        );
+        list.filter = "log".to_string();
+        list.update_filter();
+        assert_eq!(list.filtered_indices, vec![2, 0, 1]);
+        assert_eq!(list.list_state.selected(), Some(0));
+
+        list.filter = "flogin".to_string();
+        list.update_filter();
+        assert_eq!(list.filtered_indices, vec![2]);
+    }
+
+    #[test]
+    fn filter_ties_keep_original_order() {
+        let mut list = SelectList::new(
+            "test",
+            vec!["b/x".into(), "a/x".into(), "c/y".into()],
+            vec![],
+        );
+        list.filter = "x".to_string();
+        list.update_filter();
+        assert_eq!(list.filtered_indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn highlights_matched_characters() {
+        let spans = highlight_matches("feature/login", &[0, 8, 9]);
+        let parts: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style == theme::MATCH))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("f", true),
+                ("eature/", false),
+                ("lo", true),
+                ("gin", false),
+            ]
+        );
+        assert_eq!(highlight_matches("main", &[]).len(), 1);
+    }
+
+    #[test]
+    fn renders_matches_highlighted() {
+        use crate::tui::test_helpers::test_terminal;
+
+        let mut terminal = test_terminal(40, 10);
+        let mut list = SelectList::new("Pick", vec!["main".into(), "feature/login".into()], vec![]);
+        list.filter = "flo".to_string();
+        list.update_filter();
+        terminal
+            .draw(|frame| list.render(frame, Rect::new(0, 0, 40, 10)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        // The item row starts with "    " after the highlight symbol
+        let row = (0..10)
+            .find(|&y| {
+                (0..40)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .contains("feature/login")
+            })
+            .unwrap();
+        let styled: String = (0..40)
+            .filter(|&x| {
+                buffer[(x, row)]
+                    .modifier
+                    .contains(ratatui::style::Modifier::BOLD)
+            })
+            .map(|x| buffer[(x, row)].symbol())
+            .collect();
+        assert_eq!(styled, "flo");
+    }
+
+    #[test]
+    fn renders_hints_in_a_column() {
+        use crate::tui::test_helpers::{buffer_text, test_terminal};
+
+        let mut terminal = test_terminal(40, 10);
+        let list = SelectList::new("Pick", vec!["main".into(), "feature".into()], vec![])
+            .with_hints(vec![vec![Span::raw("2d ago")], vec![Span::raw("just now")]]);
+        terminal
+            .draw(|frame| list.render(frame, Rect::new(0, 0, 40, 10)))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("main     2d ago"), "expected hint in: {text}");
+        assert!(
+            text.contains("feature  just now"),
+            "expected hint in: {text}"
+        );
+    }
+
     #[test]
     fn move_wraps_around() {
         let mut list = SelectList::new("test", vec!["a".into(), "b".into()], vec![]);
@@ -260,6 +638,97 @@ mod tests {
         assert_eq!(list.total_visible(), 2);
     }
 
+    #[test]
+    fn multi_select_toggles_and_returns_checked() {
+        let mut list = SelectList::new("test", vec!["a".into(), "b".into(), "c".into()], vec![])
+            .with_multi_select([2]);
+        list.handle_event(&crate::tui::test_helpers::key_char(' '));
+        match list.handle_event(&crate::tui::test_helpers::enter()) {
+            Action::Selected(result) => assert_eq!(result, SelectResult::Items(vec![0, 2])),
+            _ => panic!("expected Selected"),
+        }
+    }
+
+    #[test]
+    fn multi_select_space_does_not_filter() {
+        let mut list =
+            SelectList::new("test", vec!["a".into(), "b".into()], vec![]).with_multi_select([]);
+        list.handle_event(&crate::tui::test_helpers::key_char(' '));
+        assert!(list.filter.is_empty());
+        assert!(list.checked.contains(&0));
+        list.handle_event(&crate::tui::test_helpers::key_char(' '));
+        assert!(list.checked.is_empty());
+    }
+
+    #[test]
+    fn multi_select_toggle_all_visible() {
+        use crate::tui::test_helpers::{key_char, type_string};
+
+        let mut list = SelectList::new(
+            "test",
+            vec!["feat/a".into(), "feat/b".into(), "main".into()],
+            vec![],
+        )
+        .with_multi_select([]);
+        list.handle_event(&key_char('a'));
+        assert_eq!(list.checked, BTreeSet::from([0, 1, 2]));
+        list.handle_event(&key_char('a'));
+        assert!(list.checked.is_empty());
+
+        // With a filter, `a` is typed and Ctrl+A toggles what is visible
+        for event in type_string("feat/a") {
+            list.handle_event(&event);
+        }
+        assert_eq!(list.filter, "feat/a");
+        list.filter = "feat".into();
+        list.update_filter();
+        list.handle_event(&ctrl_a());
+        assert_eq!(list.checked, BTreeSet::from([0, 1]));
+    }
+
+    #[test]
+    fn multi_select_keeps_checks_across_filter_changes() {
+        use crate::tui::test_helpers::{enter, key_char, key_event};
+
+        let mut list = SelectList::new(
+            "test",
+            vec!["alpha".into(), "beta".into(), "gamma".into()],
+            vec![],
+        )
+        .with_multi_select([]);
+        list.handle_event(&key_char('g'));
+        list.handle_event(&key_char(' '));
+        assert_eq!(list.checked, BTreeSet::from([2]));
+        list.handle_event(&key_event(KeyCode::Backspace));
+        assert_eq!(list.filtered_indices, vec![0, 1, 2]);
+        list.handle_event(&key_char(' '));
+        match list.handle_event(&enter()) {
+            Action::Selected(result) => assert_eq!(result, SelectResult::Items(vec![0, 2])),
+            _ => panic!("expected Selected"),
+        }
+    }
+
+    #[test]
+    fn multi_select_header_counts_selection() {
+        use crate::tui::test_helpers::{buffer_text, test_terminal};
+
+        let mut terminal = test_terminal(60, 10);
+        let list = SelectList::new("Pick", vec!["a".into(), "b".into(), "c".into()], vec![])
+            .with_multi_select([0, 2]);
+        terminal
+            .draw(|frame| list.render(frame, Rect::new(0, 0, 60, 10)))
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(
+            text.contains("Pick  (2 selected)"),
+            "expected count in: {text}"
+        );
+    }
+
+    fn ctrl_a() -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL))
+    }
+
     #[test]
     fn renders_label_and_items() {
         use crate::tui::test_helpers::{buffer_text, test_terminal};
@@ -54,3 +54,18 @@ pub fn run_tui<T>(f: impl FnOnce(&mut Tui) -> anyhow::Result<T>) -> anyhow::Resu
 
     result
 }
+
+/// Hand the terminal back to the shell while `f` runs (e.g. to run a child
+/// process that prints or prompts), then re-enter the TUI.
+pub fn suspend<T>(terminal: &mut Tui, f: impl FnOnce() -> T) -> anyhow::Result<T> {
+    restore_terminal(terminal);
+    let result = f();
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+    Ok(result)
+}
@@ -17,6 +17,8 @@ pub struct TextInput {
     pub cursor: usize,
     pub default: Option<String>,
     pub label: String,
+    /// Validation message shown under the input.
+    pub error: Option<String>,
 }
 
 impl TextInput {
@@ -26,6 +28,7 @@ impl TextInput {
             cursor: 0,
             default: None,
             label: label.into(),
+            error: None,
         }
     }
 
@@ -162,6 +165,16 @@ impl TextInput {
             Rect::new(area.x, y, area.width, 1),
         );
 
+        if let Some(error) = &self.error
+            && area.height > 2
+        {
+            let error_line = Line::from(Span::styled(format!("    {error}"), theme::ERROR));
+            frame.render_widget(
+                Paragraph::new(error_line),
+                Rect::new(area.x, y + 1, area.width, 1),
+            );
+        }
+
         // Cursor position: "  > " is 4 chars, then cursor offset into value
         let cursor_col = 4 + self.value[..self.cursor].chars().count() as u16;
         frame.set_cursor_position((area.x + cursor_col, y));
@@ -298,4 +311,26 @@ mod tests {
         let text = buffer_text(&terminal);
         assert!(text.contains("> main"), "expected default in: {text}");
     }
+
+    #[test]
+    fn renders_error_below_input() {
+        use crate::tui::test_helpers::{buffer_text, test_terminal};
+        use ratatui::layout::Rect;
+
+        let mut terminal = test_terminal(60, 5);
+        let mut input = TextInput::new("test").with_initial("a..b");
+        input.error = Some("cannot contain \"..\"".into());
+        terminal
+            .draw(|frame| {
+                input.render(frame, Rect::new(0, 0, 60, 5));
+            })
+            .unwrap();
+        let text = buffer_text(&terminal);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[1].contains("> a..b"), "expected value in: {text}");
+        assert!(
+            lines[2].contains("cannot contain"),
+            "expected error below input in: {text}"
+        );
+    }
 }
@@ -6,7 +6,13 @@ pub const DIM: Style = Style::new().add_modifier(Modifier::DIM);
 pub const HIGHLIGHT: Style = Style::new().fg(Color::Cyan);
 pub const NORMAL: Style = Style::new();
 pub const ERROR: Style = Style::new().fg(Color::Red);
+pub const WARNING: Style = Style::new().fg(Color::Yellow);
+pub const MATCH: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
 
 pub const HELP_WIZARD: &str = "Enter confirm  ·  Esc back  ·  Ctrl+C cancel";
 pub const HELP_SELECT: &str = "↑↓ navigate  ·  type to filter  ·  Enter select  ·  Esc cancel";
+pub const HELP_SELECT_PREVIEW: &str =
+    "↑↓ navigate  ·  PgUp/PgDn scroll preview  ·  type to filter  ·  Enter select  ·  Esc cancel";
 pub const HELP_CONFIRM: &str = "←→ toggle  ·  y/n  ·  Enter confirm  ·  Esc back";
+pub const HELP_MULTI_SELECT: &str = "↑↓ navigate  ·  Space toggle  ·  a/Ctrl+A toggle all  ·  type to filter  ·  Enter confirm  ·  Esc cancel";
+pub const HELP_DASHBOARD: &str = "↑↓ navigate  ·  a add  ·  d remove  ·  l lock  ·  f fetch  ·  s shell  ·  y copy path  ·  Enter switch  ·  q quit";
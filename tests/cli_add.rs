@@ -2,7 +2,6 @@
 
 mod common;
 
-use assert_cmd::Command;
 use predicates::prelude::*;
 
 #[test]
@@ -18,8 +17,7 @@ fn add_new_branch() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "test-branch", "--base", "main"])
         .current_dir(&main_wt)
         .assert()
@@ -50,8 +48,7 @@ fn add_existing_local_branch() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "feature-x"])
         .current_dir(&main_wt)
         .assert()
@@ -87,8 +84,7 @@ fn add_warns_when_fetch_fails_but_continues() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "local-only"])
         .current_dir(&main_wt)
         .assert()
@@ -112,8 +108,7 @@ fn add_new_branch_non_tty_without_base_fails() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "new-branch"])
         .current_dir(&main_wt)
         .assert()
@@ -135,8 +130,7 @@ fn add_new_branch_with_base_bypasses_prompt() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "new-branch", "--base", "main"])
         .current_dir(&main_wt)
         .assert()
@@ -166,8 +160,7 @@ fn add_existing_local_branch_no_prompt() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "local-feature"])
         .current_dir(&main_wt)
         .assert()
@@ -197,8 +190,7 @@ fn add_prints_cd_hint() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "hint-branch"])
         .current_dir(&main_wt)
         .assert()
@@ -222,8 +214,7 @@ fn add_no_branch_non_tty_fails() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add"])
         .current_dir(&main_wt)
         .assert()
@@ -254,8 +245,7 @@ fn add_existing_remote_branch_no_prompt() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["add", "remote-feature"])
         .current_dir(&main_wt)
         .assert()
@@ -264,3 +254,307 @@ fn add_existing_remote_branch_no_prompt() {
 
     assert!(project_dir.join("test_remote-feature").exists());
 }
+
+/// Project with a `test_main` worktree holding an untracked `.env`, and
+/// `copy_files`/`link_files` set in `.grov.toml`.
+fn setup_carry_files(
+    copy: &str,
+    link: &str,
+) -> (tempfile::TempDir, std::path::PathBuf, std::path::PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    std::fs::write(main_wt.join(".env"), "SECRET=1\n").unwrap();
+    std::fs::write(
+        bare.join(".grov.toml"),
+        format!("[worktree]\nprefix = \"test\"\ncopy_files = [{copy}]\nlink_files = [{link}]\n"),
+    )
+    .unwrap();
+    (tmp, bare, project_dir)
+}
+
+#[test]
+fn add_copies_configured_files_from_current_worktree() {
+    let (_tmp, _bare, project_dir) = setup_carry_files("\".env\", \".env.local\"", "");
+
+    common::grov()
+        .args(["add", "carry", "--base", "main"])
+        .current_dir(project_dir.join("test_main"))
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Files from test_main")
+                .and(predicate::str::contains("copied    .env"))
+                .and(predicate::str::contains("skipped   .env.local (no match)")),
+        );
+
+    let copied = std::fs::read_to_string(project_dir.join("test_carry/.env")).unwrap();
+    assert_eq!(copied, "SECRET=1\n");
+}
+
+#[test]
+fn add_links_configured_files_and_reports_conflicts() {
+    let (_tmp, _bare, project_dir) = setup_carry_files("", "\".env\", \"README.md\"");
+
+    common::grov()
+        .args(["add", "carry", "--base", "main"])
+        .current_dir(project_dir.join("test_main"))
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("linked    .env")
+                .and(predicate::str::contains("conflict  README.md")),
+        );
+
+    let link = std::fs::read_link(project_dir.join("test_carry/.env")).unwrap();
+    assert!(link.ends_with("test_main/.env"));
+}
+
+#[test]
+fn add_copy_from_other_worktree() {
+    let (_tmp, bare, project_dir) = setup_carry_files("\".env\"", "");
+    let other = project_dir.join("test_other");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args([
+            "worktree",
+            "add",
+            "-b",
+            "other",
+            other.to_str().unwrap(),
+            "main",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    std::fs::write(other.join(".env"), "OTHER=1\n").unwrap();
+
+    common::grov()
+        .args(["add", "carry", "--base", "main", "--copy-from", "other"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success();
+
+    let copied = std::fs::read_to_string(project_dir.join("test_carry/.env")).unwrap();
+    assert_eq!(copied, "OTHER=1\n");
+}
+
+#[test]
+fn add_copy_from_unknown_worktree_fails_before_creating() {
+    let (_tmp, _bare, project_dir) = setup_carry_files("\".env\"", "");
+
+    common::grov()
+        .args(["add", "carry", "--base", "main", "--copy-from", "nope"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("worktree not found: nope"));
+
+    assert!(!project_dir.join("test_carry").exists());
+}
+
+#[test]
+fn add_copies_from_default_branch_worktree_outside_worktrees() {
+    let (_tmp, _bare, project_dir) = setup_carry_files("\".env\"", "");
+
+    common::grov()
+        .args(["add", "carry", "--base", "main"])
+        .current_dir(&project_dir)
+        .assert()
+        .success();
+
+    assert!(project_dir.join("test_carry/.env").exists());
+}
+
+#[test]
+fn add_copies_from_default_branch_worktree_inside_bare_repo() {
+    let (_tmp, bare, project_dir) = setup_carry_files("\".env\"", "");
+
+    common::grov()
+        .args(["add", "carry", "--base", "main"])
+        .current_dir(&bare)
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Files from test_main"));
+
+    let copied = std::fs::read_to_string(project_dir.join("test_carry/.env")).unwrap();
+    assert_eq!(copied, "SECRET=1\n");
+}
+
+#[test]
+fn add_uses_path_template() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\npath_template = \"trees/{ticket}-{branch_leaf}\"\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .args(["add", "feat/ABC-12/login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+    let wt = project_dir.join("trees/ABC-12-login");
+    assert!(wt.join("README.md").exists());
+
+    // Nested worktrees still resolve by directory name
+    common::grov()
+        .args(["remove", "trees/ABC-12-login", "--match", "dir"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+    assert!(!wt.exists());
+}
+
+#[test]
+fn add_path_template_outside_project_fails() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\npath_template = \"../{branch}\"\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .args(["add", "escape", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not inside the project directory"));
+}
+
+#[test]
+fn add_explains_sanitized_name_collision() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let taken = project_dir.join("test_feature-login");
+    for (path, extra) in [(&main_wt, vec![]), (&taken, vec!["-b", "feature-login"])] {
+        let mut args = vec!["worktree", "add"];
+        args.extend(extra);
+        args.extend([path.to_str().unwrap(), "main"]);
+        let output = std::process::Command::new("git")
+            .env("GIT_DIR", &bare)
+            .args(&args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+
+    common::grov()
+        .args(["add", "feature/login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "which is already the worktree of branch feature-login",
+        ));
+
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\non_collision = \"suffix\"\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .args(["add", "feature/login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("using"));
+    assert!(project_dir.join("test_feature-login-2").exists());
+}
+
+#[test]
+fn add_rejects_invalid_branch_name() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    common::grov()
+        .args(["add", "feat..login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid branch name `feat..login`: cannot contain \"..\"",
+        ));
+    assert!(!project_dir.join("test_feat..login").exists());
+}
+
+#[test]
+fn add_enforces_branch_naming_policy_for_new_branches() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\n\n[branch]\nprefixes = [\"feat/\", \"fix/\"]\npattern = \"[A-Z]+-\\\\d+\"\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .args(["add", "login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must start with feat/, fix/"));
+
+    common::grov()
+        .args(["add", "feat/login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must match `[A-Z]+-\\d+`"));
+
+    common::grov()
+        .args(["add", "feat/ABC-1-login", "--base", "main"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+
+    // Existing branches are checked out whatever their name
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["branch", "legacy", "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    common::grov()
+        .args(["add", "legacy"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+}
@@ -0,0 +1,242 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::Path;
+
+use predicates::prelude::*;
+
+fn add_main_worktree(bare: &Path, project_dir: &Path) {
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args([
+            "worktree",
+            "add",
+            project_dir.join("test_main").to_str().unwrap(),
+            "main",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to add worktree");
+}
+
+/// Write a global config under `<tmp>/xdg` and return the XDG_CONFIG_HOME to use.
+fn write_global_config(tmp: &Path, contents: &str) -> std::path::PathBuf {
+    let xdg = tmp.join("xdg");
+    std::fs::create_dir_all(xdg.join("grov")).unwrap();
+    std::fs::write(xdg.join("grov/config.toml"), contents).unwrap();
+    xdg
+}
+
+#[test]
+fn config_show_merges_global_repo_and_env() {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    add_main_worktree(&bare, &project_dir);
+    let xdg = write_global_config(
+        tmp.path(),
+        "[worktree]\nprefix = \"global\"\n\n[hooks]\npost_add = [\"echo hi\"]\n",
+    );
+
+    common::grov()
+        .args(["config", "show", "--origin"])
+        .current_dir(project_dir.join("test_main"))
+        .env("XDG_CONFIG_HOME", &xdg)
+        .env("GROV_WORKTREE_COPY_FILES", ".env")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("repo:")
+                .and(predicate::str::contains("worktree.prefix = \"test\""))
+                .and(predicate::str::contains("global:"))
+                .and(predicate::str::contains("hooks.post_add = [\"echo hi\"]"))
+                .and(predicate::str::contains(
+                    "env:GROV_WORKTREE_COPY_FILES\tworktree.copy_files = [\".env\"]",
+                ))
+                .and(predicate::str::contains("default\thooks.pre_remove = []")),
+        );
+}
+
+#[test]
+fn config_show_outside_project_uses_global_only() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let xdg = write_global_config(tmp.path(), "[worktree]\nprefix = \"g\"\n");
+
+    common::grov()
+        .args(["config", "show"])
+        .current_dir(tmp.path())
+        .env("XDG_CONFIG_HOME", &xdg)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("worktree.prefix = \"g\""));
+}
+
+#[test]
+fn global_hooks_apply_to_every_project() {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    add_main_worktree(&bare, &project_dir);
+    let xdg = write_global_config(tmp.path(), "[hooks]\npost_add = [\"touch from-global\"]\n");
+
+    common::grov()
+        .args(["add", "globally", "--base", "main"])
+        .current_dir(project_dir.join("test_main"))
+        .env("XDG_CONFIG_HOME", &xdg)
+        .assert()
+        .success();
+
+    assert!(project_dir.join("test_globally/from-global").exists());
+}
+
+#[test]
+fn config_validate_reports_problems_with_position() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    add_main_worktree(&bare, &project_dir);
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\nprefx = \"typo\"\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .args(["config", "validate"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains(".grov.toml:3:1: unknown key `worktree.prefx`")
+                .and(predicate::str::contains("found 1 problem")),
+        );
+}
+
+#[test]
+fn config_validate_accepts_explicit_files() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let good = tmp.path().join("good.toml");
+    std::fs::write(&good, "version = 1\n[worktree]\nprefix = \"p\"\n").unwrap();
+
+    common::grov()
+        .args(["config", "validate", good.to_str().unwrap()])
+        .current_dir(tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("good.toml"));
+
+    common::grov()
+        .args(["config", "validate", "missing.toml"])
+        .current_dir(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no such file: missing.toml"));
+}
+
+#[test]
+fn malformed_config_fails_add_instead_of_using_defaults() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    add_main_worktree(&bare, &project_dir);
+    std::fs::write(bare.join(".grov.toml"), "[worktree]\nprefix = 5\n").unwrap();
+
+    common::grov()
+        .args(["add", "typo", "--base", "main"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "2:10: `worktree.prefix` expects a string, found integer",
+        ));
+
+    assert!(!project_dir.join("typo").exists());
+}
+
+#[test]
+fn config_set_get_unset_round_trip() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    add_main_worktree(&bare, &project_dir);
+    let main_wt = project_dir.join("test_main");
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "# keep me\n[worktree]\nprefix = \"test\"\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .args(["config", "set", "worktree.prefix", "proj"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Set worktree.prefix = \"proj\""));
+
+    common::grov()
+        .args(["config", "set", "hooks.post_add", "npm ci", "make"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+
+    let contents = std::fs::read_to_string(bare.join(".grov.toml")).unwrap();
+    assert!(contents.starts_with("# keep me\n"), "contents: {contents}");
+
+    common::grov()
+        .args(["config", "get", "hooks.post_add"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout("npm ci\nmake\n");
+
+    common::grov()
+        .args(["config", "unset", "worktree.prefix"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+
+    common::grov()
+        .args(["config", "get", "worktree.prefix"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout("\n");
+}
+
+#[test]
+fn config_set_global_writes_user_file() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let xdg = tmp.path().join("xdg");
+
+    common::grov()
+        .args(["config", "set", "--global", "hooks.post_add", "npm ci"])
+        .current_dir(tmp.path())
+        .env("XDG_CONFIG_HOME", &xdg)
+        .assert()
+        .success();
+
+    let contents = std::fs::read_to_string(xdg.join("grov/config.toml")).unwrap();
+    assert_eq!(
+        contents,
+        "version = 1\n\n[hooks]\npost_add = [\"npm ci\"]\n"
+    );
+
+    common::grov()
+        .args(["config", "get", "--global", "hooks.post_add"])
+        .current_dir(tmp.path())
+        .env("XDG_CONFIG_HOME", &xdg)
+        .assert()
+        .success()
+        .stdout("npm ci\n");
+}
+
+#[test]
+fn config_set_rejects_unknown_keys_and_bad_types() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    add_main_worktree(&bare, &project_dir);
+
+    common::grov()
+        .args(["config", "set", "worktree.prefx", "p"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown key `worktree.prefx`"));
+
+    common::grov()
+        .args(["config", "set", "worktree.prefix", "a", "b"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("takes a single value"));
+}
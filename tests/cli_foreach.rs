@@ -0,0 +1,162 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::*;
+
+fn add_worktree(bare: &Path, path: &Path, args: &[&str]) {
+    let mut cmd_args = vec!["worktree", "add"];
+    cmd_args.extend(args);
+    cmd_args.push(path.to_str().unwrap());
+    cmd_args.push("main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(&cmd_args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to add worktree");
+}
+
+/// main, feature/a and feature/b worktrees; feature/b is dirty.
+fn setup() -> (tempfile::TempDir, PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    add_worktree(&bare, &project_dir.join("test_main"), &[]);
+    add_worktree(
+        &bare,
+        &project_dir.join("test_feature-a"),
+        &["-b", "feature/a"],
+    );
+    add_worktree(
+        &bare,
+        &project_dir.join("test_feature-b"),
+        &["-b", "feature/b"],
+    );
+    std::fs::write(project_dir.join("test_feature-b/README.md"), "changed\n").unwrap();
+    (tmp, project_dir)
+}
+
+#[test]
+fn foreach_runs_in_every_worktree() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args(["foreach", "--", "git", "rev-parse", "--abbrev-ref", "HEAD"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("main")
+                .and(predicate::str::contains("feature/a"))
+                .and(predicate::str::contains("feature/b"))
+                .and(predicate::str::contains("\u{2713}")),
+        );
+}
+
+#[test]
+fn foreach_branch_glob_filter() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args(["foreach", "--branch", "feature/*", "--", "pwd"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("test_feature-a")
+                .and(predicate::str::contains("test_feature-b"))
+                .and(predicate::str::contains("test_main").not()),
+        );
+}
+
+#[test]
+fn foreach_dirty_and_clean_filters() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args(["foreach", "--dirty", "--", "pwd"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("test_feature-b")
+                .and(predicate::str::contains("test_feature-a").not()),
+        );
+
+    common::grov()
+        .args(["foreach", "--clean", "--", "pwd"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("test_feature-a")
+                .and(predicate::str::contains("test_feature-b").not()),
+        );
+}
+
+#[test]
+fn foreach_parallel_prefixes_output() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args([
+            "foreach",
+            "-j",
+            "3",
+            "--",
+            "git",
+            "rev-parse",
+            "--abbrev-ref",
+            "HEAD",
+        ])
+        .current_dir(project_dir.join("test_main"))
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("[feature/a] feature/a")
+                .and(predicate::str::contains("[feature/b] feature/b"))
+                .and(predicate::str::contains("[main     ] main")),
+        );
+}
+
+#[test]
+fn foreach_reports_failures_and_exits_non_zero() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args(["foreach", "-j", "2", "--", "git", "diff", "--quiet"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("exit 1"))
+        .stderr(predicate::str::contains(
+            "command failed in 1 of 3 worktrees",
+        ));
+}
+
+#[test]
+fn foreach_missing_command_fails() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args(["foreach", "--", "grov-definitely-not-a-command"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "command failed in 3 of 3 worktrees",
+        ));
+}
+
+#[test]
+fn foreach_requires_command() {
+    let (_tmp, project_dir) = setup();
+
+    common::grov()
+        .args(["foreach"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure();
+}
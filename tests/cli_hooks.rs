@@ -0,0 +1,199 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::*;
+
+fn add_worktree(bare: &Path, path: &Path, args: &[&str]) {
+    let mut cmd_args = vec!["worktree", "add"];
+    cmd_args.extend(args);
+    cmd_args.push(path.to_str().unwrap());
+    cmd_args.push("main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(&cmd_args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to add worktree");
+}
+
+/// Project with a main worktree and the given `[hooks]` section appended to `.grov.toml`.
+fn setup(hooks: &str) -> (tempfile::TempDir, PathBuf, PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    add_worktree(&bare, &project_dir.join("test_main"), &[]);
+    let config_path = bare.join(".grov.toml");
+    let mut config = std::fs::read_to_string(&config_path).unwrap();
+    config.push_str("\n[hooks]\n");
+    config.push_str(hooks);
+    std::fs::write(&config_path, config).unwrap();
+    (tmp, bare, project_dir)
+}
+
+#[test]
+fn post_add_runs_in_new_worktree_with_env() {
+    let (_tmp, _bare, project_dir) =
+        setup("post_add = [\"printf '%s' \\\"$GROV_BRANCH\\\" > hook.txt\"]\n");
+
+    common::grov()
+        .args(["add", "hooked", "--base", "main"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("post_add hook"));
+
+    let written = std::fs::read_to_string(project_dir.join("test_hooked/hook.txt")).unwrap();
+    assert_eq!(written, "hooked");
+}
+
+#[test]
+fn no_hooks_skips_post_add() {
+    let (_tmp, _bare, project_dir) = setup("post_add = [\"touch hook.txt\"]\n");
+
+    common::grov()
+        .args(["add", "hooked", "--base", "main", "--no-hooks"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success();
+
+    assert!(project_dir.join("test_hooked").exists());
+    assert!(!project_dir.join("test_hooked/hook.txt").exists());
+}
+
+#[test]
+fn failing_post_add_warns_but_succeeds() {
+    let (_tmp, _bare, project_dir) = setup("post_add = [\"exit 3\"]\n");
+
+    common::grov()
+        .args(["add", "hooked", "--base", "main"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("warning:").and(predicate::str::contains("exit 3")));
+
+    assert!(project_dir.join("test_hooked").exists());
+}
+
+#[test]
+fn failing_pre_remove_aborts_removal() {
+    let (_tmp, bare, project_dir) = setup("pre_remove = [\"exit 1\"]\n");
+    let wt = project_dir.join("test_doomed");
+    add_worktree(&bare, &wt, &["-b", "doomed"]);
+
+    common::grov()
+        .args(["remove", "doomed"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("aborting removal")
+                .and(predicate::str::contains("pre_remove hook")),
+        );
+
+    assert!(wt.exists());
+
+    common::grov()
+        .args(["remove", "doomed", "--no-hooks"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success();
+
+    assert!(!wt.exists());
+}
+
+#[test]
+fn pre_and_post_remove_receive_worktree_env() {
+    let (_tmp, bare, project_dir) = setup(
+        "pre_remove = [\"printf '%s' \\\"$GROV_WORKTREE\\\" > ../pre.txt\"]\n\
+         post_remove = [\"printf '%s' \\\"$GROV_BRANCH\\\" > post.txt\"]\n",
+    );
+    let wt = project_dir.join("test_doomed");
+    add_worktree(&bare, &wt, &["-b", "doomed"]);
+
+    common::grov()
+        .args(["remove", "doomed"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success();
+
+    assert!(!wt.exists());
+    let pre = std::fs::read_to_string(project_dir.join("pre.txt")).unwrap();
+    assert!(
+        pre.ends_with("test_doomed"),
+        "unexpected GROV_WORKTREE: {pre}"
+    );
+    // post_remove runs in the project directory
+    let post = std::fs::read_to_string(project_dir.join("post.txt")).unwrap();
+    assert_eq!(post, "doomed");
+}
+
+/// `grov init` from a remote whose tracked `.grov.toml` has its own
+/// `post_init`, with `hooks.post_init` set in the user-level config. The
+/// project's config is written by `init`, so only the user-level hook applies.
+fn init_with_post_init(extra: &[&str]) -> (tempfile::TempDir, PathBuf) {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let source = common::create_source_repo(tmp.path());
+    std::fs::write(
+        source.join(".grov.toml"),
+        "[hooks]\npost_init = [\"touch from-remote.txt\"]\n",
+    )
+    .unwrap();
+    let remote = tmp.path().join("source.git");
+    for args in [
+        vec!["add", ".grov.toml"],
+        vec!["commit", "-m", "add grov config"],
+        vec![
+            "clone",
+            "--bare",
+            source.to_str().unwrap(),
+            remote.to_str().unwrap(),
+        ],
+    ] {
+        let output = std::process::Command::new("git")
+            .args(&args)
+            .current_dir(&source)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?} failed");
+    }
+
+    let config_home = tmp.path().join("config");
+    std::fs::create_dir_all(config_home.join("grov")).unwrap();
+    std::fs::write(
+        config_home.join("grov/config.toml"),
+        "[hooks]\npost_init = [\"printf '%s' \\\"$GROV_BRANCH\\\" > hook.txt\"]\n",
+    )
+    .unwrap();
+
+    let parent = tmp.path().join("projects");
+    std::fs::create_dir_all(&parent).unwrap();
+    common::grov()
+        .args(["init", "--url", remote.to_str().unwrap()])
+        .args(extra)
+        .current_dir(&parent)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .assert()
+        .success();
+
+    let main_wt = parent.join("source/main");
+    assert!(main_wt.join(".grov.toml").exists());
+    (tmp, main_wt)
+}
+
+#[test]
+fn post_init_runs_in_new_worktree() {
+    let (_tmp, main_wt) = init_with_post_init(&[]);
+
+    let written = std::fs::read_to_string(main_wt.join("hook.txt")).unwrap();
+    assert_eq!(written, "main");
+    // Commands from the cloned repository are never run
+    assert!(!main_wt.join("from-remote.txt").exists());
+}
+
+#[test]
+fn no_hooks_skips_post_init() {
+    let (_tmp, main_wt) = init_with_post_init(&["--no-hooks"]);
+
+    assert!(!main_wt.join("hook.txt").exists());
+}
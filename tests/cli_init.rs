@@ -1,3 +1,143 @@
-// Init is now TUI-only (no CLI flags for url/name/prefix/branch).
-// Interactive TUI commands cannot be tested via assert_cmd.
-// The init wizard is tested manually.
+#![allow(deprecated)]
+
+mod common;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn init_with_all_flags() {
+    let tmp = TempDir::new().unwrap();
+    let remote = common::create_bare_remote(tmp.path());
+
+    common::grov()
+        .args([
+            "init",
+            "--url",
+            remote.to_str().unwrap(),
+            "--name",
+            "myproject",
+            "--prefix",
+            "mp",
+            "--branch",
+            "main",
+        ])
+        .current_dir(tmp.path())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Initialized")
+                .and(predicate::str::contains("myproject/repo.git"))
+                .and(predicate::str::contains("myproject/mp_main")),
+        );
+
+    let project_dir = tmp.path().join("myproject");
+    assert!(project_dir.join("repo.git").is_dir());
+    assert!(project_dir.join("mp_main/README.md").exists());
+
+    let config = std::fs::read_to_string(project_dir.join("repo.git/.grov.toml")).unwrap();
+    assert!(config.contains("prefix = \"mp\""), "config: {config}");
+    assert!(config.starts_with("version = 1\n"), "config: {config}");
+}
+
+#[test]
+fn init_non_tty_derives_missing_values() {
+    let tmp = TempDir::new().unwrap();
+    let remote = common::create_bare_remote(tmp.path());
+    let parent = tmp.path().join("projects");
+    std::fs::create_dir_all(&parent).unwrap();
+
+    common::grov()
+        .args(["init", "--url", remote.to_str().unwrap()])
+        .current_dir(&parent)
+        .assert()
+        .success();
+
+    // Name is derived from the URL minus `.git`, prefix is blank, branch is detected
+    let project_dir = parent.join("source");
+    assert!(project_dir.join("repo.git").is_dir());
+    assert!(project_dir.join("main/README.md").exists());
+}
+
+#[test]
+fn init_with_path_flag() {
+    let tmp = TempDir::new().unwrap();
+    let remote = common::create_bare_remote(tmp.path());
+    let parent = tmp.path().join("projects");
+    std::fs::create_dir_all(&parent).unwrap();
+
+    common::grov()
+        .args([
+            "init",
+            "--url",
+            remote.to_str().unwrap(),
+            "--name",
+            "proj",
+            "--path",
+            parent.to_str().unwrap(),
+        ])
+        .current_dir(tmp.path())
+        .assert()
+        .success();
+
+    assert!(parent.join("proj/main").exists());
+}
+
+#[test]
+fn init_custom_branch() {
+    let tmp = TempDir::new().unwrap();
+    let remote = common::create_bare_remote(tmp.path());
+
+    let output = std::process::Command::new("git")
+        .current_dir(&remote)
+        .args(["branch", "develop", "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    common::grov()
+        .args([
+            "init",
+            "--url",
+            remote.to_str().unwrap(),
+            "--name",
+            "proj",
+            "--prefix",
+            "p",
+            "--branch",
+            "develop",
+        ])
+        .current_dir(tmp.path())
+        .assert()
+        .success();
+
+    assert!(tmp.path().join("proj/p_develop").exists());
+}
+
+#[test]
+fn init_existing_directory_fails() {
+    let tmp = TempDir::new().unwrap();
+    let remote = common::create_bare_remote(tmp.path());
+    std::fs::create_dir_all(tmp.path().join("taken")).unwrap();
+
+    common::grov()
+        .args(["init", "--url", remote.to_str().unwrap(), "--name", "taken"])
+        .current_dir(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("directory already exists"));
+}
+
+#[test]
+fn init_non_tty_without_url_fails() {
+    let tmp = TempDir::new().unwrap();
+
+    common::grov()
+        .args(["init", "--name", "proj"])
+        .current_dir(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--url is required when stdin is not a terminal",
+        ));
+}
@@ -2,7 +2,6 @@
 
 mod common;
 
-use assert_cmd::Command;
 use predicates::prelude::*;
 
 #[test]
@@ -18,8 +17,7 @@ fn list_in_worktree() {
         .unwrap();
     assert!(output.status.success(), "failed to add worktree");
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["list"])
         .current_dir(&wt_path)
         .assert()
@@ -39,8 +37,7 @@ fn list_compact() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["list", "--compact"])
         .current_dir(&wt_path)
         .assert()
@@ -63,8 +60,7 @@ fn list_marks_current_from_nested_subdir() {
     let nested = wt_path.join("nested/deep");
     std::fs::create_dir_all(&nested).unwrap();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["list"])
         .current_dir(&nested)
         .assert()
@@ -101,8 +97,7 @@ fn list_marks_missing_worktree() {
 
     std::fs::remove_dir_all(&missing_wt).unwrap();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["list"])
         .current_dir(&main_wt)
         .assert()
@@ -111,3 +106,340 @@ fn list_marks_missing_worktree() {
             predicate::str::contains("missing-branch").and(predicate::str::contains("! missing")),
         );
 }
+
+fn add_main_worktree(bare: &std::path::Path, project_dir: &std::path::Path) -> std::path::PathBuf {
+    let wt_path = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(["worktree", "add", wt_path.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    wt_path
+}
+
+#[test]
+fn list_format_json() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    std::fs::write(wt_path.join("scratch.txt"), "dirty\n").unwrap();
+
+    let output = common::grov()
+        .args(["list", "--format", "json"])
+        .current_dir(&wt_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let entries: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let entries = entries.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+
+    let main = &entries[0];
+    assert_eq!(main["branch"], "main");
+    assert_eq!(main["dir_name"], "test_main");
+    assert_eq!(main["is_bare"], false);
+    assert_eq!(main["is_detached"], false);
+    assert_eq!(main["is_current"], true);
+    assert_eq!(main["status"], "dirty");
+    assert_eq!(main["head"].as_str().unwrap().len(), 40);
+    for key in ["path", "ahead", "behind", "upstream"] {
+        assert!(main.get(key).is_some(), "missing key {key}: {main}");
+    }
+}
+
+#[test]
+fn list_format_tsv() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+
+    let output = common::grov()
+        .args(["list", "--format", "tsv"])
+        .current_dir(&wt_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let main_line = stdout
+        .lines()
+        .find(|line| line.contains("test_main"))
+        .expect("main worktree line");
+    let fields: Vec<&str> = main_line.split('\t').collect();
+    assert_eq!(fields.len(), 13);
+    assert_eq!(fields[1], "test_main");
+    assert_eq!(fields[2], "main");
+    assert_eq!(fields[6], "true");
+    assert_eq!(fields[7], "clean");
+    assert_eq!(fields[11], "false");
+}
+
+#[test]
+fn list_format_porcelain() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+
+    let output = common::grov()
+        .args(["list", "--format", "porcelain"])
+        .current_dir(&wt_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains('\n'), "porcelain must be NUL-delimited");
+
+    let records: Vec<&str> = stdout
+        .split("\0\0")
+        .filter(|record| !record.is_empty())
+        .collect();
+    assert_eq!(records.len(), 1);
+
+    let main: Vec<&str> = records[0].split('\0').collect();
+    assert!(main.contains(&"dir test_main"));
+    assert!(main.contains(&"branch main"));
+    assert!(main.contains(&"current"));
+    assert!(main.contains(&"status clean"));
+}
+
+#[test]
+fn list_format_conflicts_with_compact() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+
+    common::grov()
+        .args(["list", "--compact", "--format", "json"])
+        .current_dir(&wt_path)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn list_untracked_mode_from_config() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    std::fs::write(wt_path.join("scratch.txt"), "untracked\n").unwrap();
+
+    common::grov()
+        .args(["list"])
+        .current_dir(&wt_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("dirty"));
+
+    common::grov()
+        .args(["list"])
+        .current_dir(&wt_path)
+        .env("GROV_STATUS_UNTRACKED", "no")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("clean"));
+}
+
+#[test]
+fn list_long_shows_commit_and_size_columns() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+
+    common::grov()
+        .args(["list", "--long"])
+        .current_dir(&wt_path)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("initial")
+                .and(predicate::str::contains("Test"))
+                .and(predicate::str::contains("ago").or(predicate::str::contains("just now")))
+                .and(predicate::str::is_match(r"\d(\.\d)?[BKMG]").unwrap()),
+        );
+}
+
+#[test]
+fn list_stash_column_counts_stashes_per_branch() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    std::fs::write(wt_path.join("README.md"), "changed\n").unwrap();
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=T", "-c", "user.email=t@t", "stash"])
+        .current_dir(&wt_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    common::grov()
+        .args(["list", "--columns", "stash,upstream"])
+        .current_dir(&wt_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 stash"));
+}
+
+#[test]
+fn list_sort_by_name() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    for branch in ["zeta", "alpha"] {
+        let path = project_dir.join(format!("test_{branch}"));
+        let output = std::process::Command::new("git")
+            .env("GIT_DIR", &bare)
+            .args([
+                "worktree",
+                "add",
+                "-b",
+                branch,
+                path.to_str().unwrap(),
+                "main",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+
+    common::grov()
+        .args(["list", "--compact", "--sort", "name"])
+        .current_dir(&wt_path)
+        .assert()
+        .success()
+        .stdout("alpha\nmain\nzeta\n");
+}
+
+#[test]
+fn list_rejects_unknown_configured_column() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+
+    common::grov()
+        .args(["list"])
+        .current_dir(&wt_path)
+        .env("GROV_LIST_COLUMNS", r#"["date", "colour"]"#)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown column `colour`"));
+}
+
+fn git_in(dir: &std::path::Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=T", "-c", "user.email=t@t"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// main plus `done` (one commit, merged into main), `wip` (one new commit,
+/// dirty), `old` (one commit dated 2020) and `fresh` (at main's tip).
+fn add_filter_worktrees(bare: &std::path::Path, project_dir: &std::path::Path) {
+    for branch in ["done", "wip", "old", "fresh"] {
+        // `fresh` is cut once `done` has landed
+        if branch == "fresh" {
+            let done = project_dir.join("test_done");
+            git_in(&done, &["commit", "--allow-empty", "-m", "done"]);
+            git_in(
+                &project_dir.join("test_main"),
+                &["merge", "--no-ff", "--no-edit", "done"],
+            );
+        }
+        let path = project_dir.join(format!("test_{branch}"));
+        let output = std::process::Command::new("git")
+            .env("GIT_DIR", bare)
+            .args([
+                "worktree",
+                "add",
+                "-b",
+                branch,
+                path.to_str().unwrap(),
+                "main",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+    let wip = project_dir.join("test_wip");
+    git_in(&wip, &["commit", "--allow-empty", "-m", "wip"]);
+    std::fs::write(wip.join("scratch.txt"), "dirty\n").unwrap();
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=T", "-c", "user.email=t@t"])
+        .args(["commit", "--allow-empty", "-m", "old"])
+        .env("GIT_COMMITTER_DATE", "2020-01-01T00:00:00Z")
+        .current_dir(project_dir.join("test_old"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
+fn filtered_branches(dir: &std::path::Path, filters: &[&str]) -> String {
+    let output = common::grov()
+        .args(["list", "--compact", "--sort", "name"])
+        .args(filters)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn list_filters_compose_with_compact() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    add_filter_worktrees(&bare, &project_dir);
+
+    assert_eq!(filtered_branches(&wt_path, &["--dirty"]), "wip\n");
+    assert_eq!(filtered_branches(&wt_path, &["--merged"]), "done\n");
+    assert_eq!(filtered_branches(&wt_path, &["--stale", "30"]), "old\n");
+    assert_eq!(
+        filtered_branches(&wt_path, &["--branch", "[dw]*"]),
+        "done\nwip\n"
+    );
+    assert_eq!(
+        filtered_branches(&wt_path, &["--branch", "w*", "--merged"]),
+        ""
+    );
+}
+
+#[test]
+fn list_merged_skips_branch_at_default_tip() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    add_filter_worktrees(&bare, &project_dir);
+
+    // Same answer as `grov remove --merged`, so piping one into the other is safe
+    let merged = filtered_branches(&wt_path, &["--merged"]);
+    assert!(!merged.contains("fresh"), "listed as merged: {merged}");
+}
+
+#[test]
+fn list_filters_apply_to_machine_formats() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+    add_filter_worktrees(&bare, &project_dir);
+
+    let output = common::grov()
+        .args(["list", "--format", "json", "--dirty"])
+        .current_dir(&wt_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let entries: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let entries = entries.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["branch"], "wip");
+}
+
+#[test]
+fn list_rejects_invalid_branch_glob() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let wt_path = add_main_worktree(&bare, &project_dir);
+
+    common::grov()
+        .args(["list", "--branch", "[oops"])
+        .current_dir(&wt_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid --branch glob"));
+}
@@ -0,0 +1,147 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::*;
+
+fn add_worktree(bare: &Path, path: &Path, args: &[&str]) {
+    let mut cmd_args = vec!["worktree", "add"];
+    cmd_args.extend(args);
+    cmd_args.push(path.to_str().unwrap());
+    cmd_args.push("main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(&cmd_args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to add worktree");
+}
+
+/// main and feature worktrees.
+fn setup() -> (tempfile::TempDir, PathBuf, PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    add_worktree(&bare, &project_dir.join("test_main"), &[]);
+    add_worktree(&bare, &project_dir.join("test_feature"), &["-b", "feature"]);
+    (tmp, bare, project_dir)
+}
+
+#[test]
+fn lock_shows_in_list_and_blocks_remove() {
+    let (_tmp, _bare, project_dir) = setup();
+    let main_wt = project_dir.join("test_main");
+
+    common::grov()
+        .args(["lock", "feature", "--reason", "long-running build"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Locked feature"));
+
+    common::grov()
+        .args(["list"])
+        .current_dir(&main_wt)
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("locked (long-running build)"));
+
+    common::grov()
+        .args(["list", "--format", "json"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"locked\": \"long-running build\"",
+        ));
+
+    common::grov()
+        .args(["remove", "feature", "--force"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is locked (long-running build)"));
+    assert!(project_dir.join("test_feature").exists());
+
+    common::grov()
+        .args(["lock", "feature"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already locked"));
+
+    common::grov()
+        .args(["unlock", "feature"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unlocked feature"));
+
+    common::grov()
+        .args(["remove", "feature"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+    assert!(!project_dir.join("test_feature").exists());
+}
+
+#[test]
+fn unlock_requires_locked_worktree() {
+    let (_tmp, _bare, project_dir) = setup();
+
+    common::grov()
+        .args(["unlock", "feature"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not locked"));
+}
+
+#[test]
+fn default_branch_is_protected_from_remove() {
+    let (_tmp, _bare, project_dir) = setup();
+    let feature_wt = project_dir.join("test_feature");
+
+    common::grov()
+        .args(["remove", "main"])
+        .current_dir(&feature_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "branch main is protected (worktree.protected)",
+        ));
+    assert!(project_dir.join("test_main").exists());
+
+    common::grov()
+        .args(["remove", "main", "--allow-protected"])
+        .current_dir(&feature_wt)
+        .assert()
+        .success();
+    assert!(!project_dir.join("test_main").exists());
+}
+
+#[test]
+fn protected_list_replaces_default() {
+    let (_tmp, bare, project_dir) = setup();
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\nprotected = [\"feat*\"]\n",
+    )
+    .unwrap();
+    let main_wt = project_dir.join("test_main");
+
+    common::grov()
+        .args(["remove", "feature"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("branch feature is protected"));
+
+    // main is no longer protected once the list is set explicitly
+    common::grov()
+        .args(["remove", "main"])
+        .current_dir(project_dir.join("test_feature"))
+        .assert()
+        .success();
+}
@@ -0,0 +1,169 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::*;
+
+fn git(bare: &Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// main and feature/old worktrees; feature/old tracks origin/main.
+fn setup() -> (tempfile::TempDir, PathBuf, PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let old_wt = project_dir.join("test_feature-old");
+    git(&bare, &["fetch", "origin"]);
+    git(
+        &bare,
+        &["worktree", "add", main_wt.to_str().unwrap(), "main"],
+    );
+    git(
+        &bare,
+        &[
+            "worktree",
+            "add",
+            "--track",
+            "-b",
+            "feature/old",
+            old_wt.to_str().unwrap(),
+            "origin/main",
+        ],
+    );
+    (tmp, bare, project_dir)
+}
+
+#[test]
+fn mv_renames_branch_and_moves_directory() {
+    let (_tmp, bare, project_dir) = setup();
+
+    common::grov()
+        .args(["mv", "feature/old", "feature/new"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Renamed branch")
+                .and(predicate::str::contains("Moved worktree")),
+        )
+        .stderr(predicate::str::contains("still tracking origin/main"));
+
+    let new_wt = project_dir.join("test_feature-new");
+    assert!(new_wt.join("README.md").exists());
+    assert!(!project_dir.join("test_feature-old").exists());
+    let head = std::process::Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .current_dir(&new_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), "feature/new");
+    assert!(git(&bare, &["branch", "--list", "feature/old"]).is_empty());
+}
+
+#[test]
+fn mv_to_custom_path() {
+    let (_tmp, _bare, project_dir) = setup();
+
+    common::grov()
+        .args(["mv", "feature/old", "feature/old", "--path", "../elsewhere"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed branch").not());
+
+    assert!(project_dir.join("elsewhere/README.md").exists());
+}
+
+#[test]
+fn mv_set_upstream_without_remote_branch_unsets_tracking() {
+    let (_tmp, bare, project_dir) = setup();
+
+    common::grov()
+        .args(["mv", "feature/old", "feature/new", "--set-upstream"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "origin/feature/new does not exist yet",
+        ));
+
+    let upstream = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["config", "branch.feature/new.merge"])
+        .output()
+        .unwrap();
+    assert!(!upstream.status.success(), "upstream should be unset");
+}
+
+#[test]
+fn mv_refuses_existing_target_and_branch() {
+    let (_tmp, bare, project_dir) = setup();
+    std::fs::create_dir(project_dir.join("test_taken")).unwrap();
+    git(&bare, &["branch", "other", "main"]);
+
+    common::grov()
+        .args(["mv", "feature/old", "taken"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("target already exists"));
+
+    common::grov()
+        .args(["mv", "feature/old", "other"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("branch other already exists"));
+
+    assert!(project_dir.join("test_feature-old").exists());
+}
+
+#[test]
+fn mv_refuses_locked_worktree() {
+    let (_tmp, bare, project_dir) = setup();
+    let old_wt = project_dir.join("test_feature-old");
+    git(
+        &bare,
+        &[
+            "worktree",
+            "lock",
+            "--reason",
+            "on a usb drive",
+            old_wt.to_str().unwrap(),
+        ],
+    );
+
+    common::grov()
+        .args(["mv", "feature/old", "feature/new"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is locked (on a usb drive)"));
+
+    assert!(old_wt.exists());
+    assert!(!git(&bare, &["branch", "--list", "feature/old"]).is_empty());
+}
+
+#[test]
+fn mv_rejects_invalid_branch_name() {
+    let (_tmp, _bare, project_dir) = setup();
+
+    common::grov()
+        .args(["mv", "feature/old", "feature..new"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid branch name"));
+}
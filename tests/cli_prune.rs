@@ -0,0 +1,282 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::Path;
+
+use predicates::prelude::*;
+
+fn add_worktree(bare: &Path, path: &Path, args: &[&str]) {
+    let mut cmd_args = vec!["worktree", "add"];
+    cmd_args.extend(args);
+    cmd_args.push(path.to_str().unwrap());
+    cmd_args.push("main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(&cmd_args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to add worktree");
+}
+
+fn worktree_list(bare: &Path) -> String {
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn prune_nothing_to_do() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+
+    common::grov()
+        .args(["prune"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nothing to prune."));
+}
+
+#[test]
+fn prune_dry_run_reports_without_changes() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let gone_wt = project_dir.join("test_gone");
+    add_worktree(&bare, &gone_wt, &["-b", "gone"]);
+    std::fs::remove_dir_all(&gone_wt).unwrap();
+
+    common::grov()
+        .args(["prune", "--dry-run"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("missing").and(predicate::str::contains("test_gone")));
+
+    assert!(worktree_list(&bare).contains("test_gone"));
+}
+
+#[test]
+fn prune_yes_removes_missing_registration() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let gone_wt = project_dir.join("test_gone");
+    add_worktree(&bare, &gone_wt, &["-b", "gone"]);
+    std::fs::remove_dir_all(&gone_wt).unwrap();
+
+    common::grov()
+        .args(["prune", "--yes"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pruned missing"));
+
+    let list = worktree_list(&bare);
+    assert!(!list.contains("test_gone"), "still registered: {list}");
+    assert!(list.contains("test_main"));
+}
+
+#[test]
+fn prune_yes_keeps_orphaned_directory_without_delete_dirs() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let orphan_wt = project_dir.join("test_orphan");
+    add_worktree(&bare, &orphan_wt, &["-b", "orphan"]);
+
+    // Drop the registration but leave the directory behind
+    std::fs::remove_dir_all(bare.join("worktrees/test_orphan")).unwrap();
+
+    common::grov()
+        .args(["prune", "--yes"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Skipped orphaned")
+                .and(predicate::str::contains("--delete-dirs")),
+        );
+
+    assert!(orphan_wt.exists());
+}
+
+#[test]
+fn prune_yes_delete_dirs_keeps_orphan_with_unknown_status() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let orphan_wt = project_dir.join("test_orphan");
+    add_worktree(&bare, &orphan_wt, &["-b", "orphan"]);
+    std::fs::remove_dir_all(bare.join("worktrees/test_orphan")).unwrap();
+
+    common::grov()
+        .args(["prune", "--yes", "--delete-dirs"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("status unknown"));
+
+    assert!(orphan_wt.exists());
+}
+
+#[test]
+fn prune_yes_delete_dirs_removes_clean_orphan_only() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let clean_wt = project_dir.join("test_clean");
+    add_worktree(&bare, &clean_wt, &["-b", "clean"]);
+    let dirty_wt = project_dir.join("test_dirty");
+    add_worktree(&bare, &dirty_wt, &["-b", "dirty"]);
+
+    // Moved worktrees keep their admin dirs, so git can still read their status
+    let clean_moved = project_dir.join("test_clean_moved");
+    let dirty_moved = project_dir.join("test_dirty_moved");
+    std::fs::rename(&clean_wt, &clean_moved).unwrap();
+    std::fs::rename(&dirty_wt, &dirty_moved).unwrap();
+    std::fs::write(dirty_moved.join("notes.txt"), "wip\n").unwrap();
+
+    common::grov()
+        .args(["prune", "--yes", "--delete-dirs"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Pruned orphaned")
+                .and(predicate::str::contains("uncommitted changes")),
+        );
+
+    assert!(!clean_moved.exists());
+    assert!(dirty_moved.join("notes.txt").exists());
+    assert!(main_wt.exists());
+}
+
+#[test]
+fn prune_yes_never_deletes_broken_link() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let broken = project_dir.join("test_broken");
+    std::fs::create_dir_all(&broken).unwrap();
+    std::fs::write(broken.join(".git"), "garbage\n").unwrap();
+
+    common::grov()
+        .args(["prune", "--yes", "--delete-dirs"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Skipped broken"));
+
+    assert!(broken.join(".git").exists());
+}
+
+#[test]
+fn prune_reports_broken_git_file_and_stale_admin_dir() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+
+    let broken = project_dir.join("test_broken");
+    std::fs::create_dir_all(&broken).unwrap();
+    std::fs::write(broken.join(".git"), "garbage\n").unwrap();
+
+    let stale = bare.join("worktrees/stale-admin");
+    std::fs::create_dir_all(&stale).unwrap();
+
+    common::grov()
+        .args(["prune", "--dry-run"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("broken")
+                .and(predicate::str::contains("test_broken"))
+                .and(predicate::str::contains("stale"))
+                .and(predicate::str::contains("stale-admin")),
+        );
+}
+
+#[test]
+fn prune_yes_removes_stale_admin_dir() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let stale = bare.join("worktrees/stale-admin");
+    std::fs::create_dir_all(&stale).unwrap();
+
+    common::grov()
+        .args(["prune", "--yes"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pruned stale"));
+
+    assert!(!stale.exists());
+    assert!(worktree_list(&bare).contains("test_main"));
+}
+
+#[test]
+fn prune_finds_orphans_under_path_template_dirs() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\npath_template = \"trees/{branch}\"\n",
+    )
+    .unwrap();
+    let main_wt = project_dir.join("trees/main");
+    add_worktree(&bare, &main_wt, &[]);
+    let orphan_wt = project_dir.join("trees/orphan");
+    add_worktree(&bare, &orphan_wt, &["-b", "orphan"]);
+    std::fs::remove_dir_all(bare.join("worktrees/orphan")).unwrap();
+
+    common::grov()
+        .args(["prune", "--dry-run"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("orphaned")
+                .and(predicate::str::contains("trees/orphan"))
+                .and(predicate::str::contains("trees/main").not()),
+        );
+}
+
+#[test]
+fn prune_ignores_unrelated_sibling_directories() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    std::fs::create_dir_all(project_dir.join("notes")).unwrap();
+
+    common::grov()
+        .args(["prune", "--dry-run"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nothing to prune."));
+}
+
+#[test]
+fn prune_non_tty_without_yes_fails() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let gone_wt = project_dir.join("test_gone");
+    add_worktree(&bare, &gone_wt, &["-b", "gone"]);
+    std::fs::remove_dir_all(&gone_wt).unwrap();
+
+    common::grov()
+        .args(["prune"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--yes is required when stdin is not a terminal",
+        ));
+}
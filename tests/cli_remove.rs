@@ -2,7 +2,6 @@
 
 mod common;
 
-use assert_cmd::Command;
 use predicates::prelude::*;
 use tempfile::TempDir;
 
@@ -35,8 +34,7 @@ fn remove_worktree() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "to-remove"])
         .current_dir(&main_wt)
         .assert()
@@ -77,8 +75,7 @@ fn remove_dirty_worktree_fails() {
     // Make it dirty
     std::fs::write(dirty_wt.join("dirty.txt"), "dirty").unwrap();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "dirty-branch"])
         .current_dir(&main_wt)
         .assert()
@@ -115,8 +112,7 @@ fn remove_dirty_worktree_with_force() {
 
     std::fs::write(dirty_wt.join("dirty.txt"), "dirty").unwrap();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "dirty-branch", "--force"])
         .current_dir(&main_wt)
         .assert()
@@ -151,8 +147,7 @@ fn remove_with_delete_branch() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "del-branch", "--delete-branch"])
         .current_dir(&main_wt)
         .assert()
@@ -172,8 +167,7 @@ fn remove_no_name_non_tty_fails() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove"])
         .current_dir(&main_wt)
         .assert()
@@ -210,8 +204,7 @@ fn remove_with_delete_branch_still_works() {
         .unwrap();
     assert!(output.status.success());
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "flag-branch", "--delete-branch"])
         .current_dir(&main_wt)
         .assert()
@@ -258,8 +251,7 @@ fn remove_non_tty_no_branch_delete_prompt() {
     assert!(output.status.success());
 
     // Remove without --delete-branch in non-TTY: branch should be preserved
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "keep-branch"])
         .current_dir(&main_wt)
         .assert()
@@ -282,8 +274,7 @@ fn remove_non_tty_no_branch_delete_prompt() {
 fn remove_ambiguous_auto_fails_with_candidates() {
     let (_tmp, main_wt, branch_wt, dir_wt) = setup_ambiguous_remove_case();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "foo"])
         .current_dir(&main_wt)
         .assert()
@@ -305,8 +296,7 @@ fn remove_ambiguous_auto_fails_with_candidates() {
 fn remove_ambiguous_match_branch_succeeds() {
     let (_tmp, main_wt, branch_wt, dir_wt) = setup_ambiguous_remove_case();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "foo", "--match", "branch"])
         .current_dir(&main_wt)
         .assert()
@@ -321,8 +311,7 @@ fn remove_ambiguous_match_branch_succeeds() {
 fn remove_ambiguous_match_dir_succeeds() {
     let (_tmp, main_wt, branch_wt, dir_wt) = setup_ambiguous_remove_case();
 
-    Command::cargo_bin("grov")
-        .unwrap()
+    common::grov()
         .args(["remove", "foo", "--match", "dir"])
         .current_dir(&main_wt)
         .assert()
@@ -383,3 +372,271 @@ fn setup_ambiguous_remove_case() -> (
 
     (tmp, main_wt, branch_wt, dir_wt)
 }
+
+fn git_in(dir: &std::path::Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.email=test@test.com", "-c", "user.name=Test"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn add_branch_worktree(bare: &std::path::Path, path: &std::path::Path, branch: &str, start: &str) {
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args([
+            "worktree",
+            "add",
+            "-b",
+            branch,
+            path.to_str().unwrap(),
+            start,
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// main worktree plus `merged` and `dirty-merged` (one commit each, both
+/// pushed and landed on origin/main, the latter with an uncommitted file) and
+/// `unmerged` (one commit).
+fn setup_merged_case() -> (TempDir, std::path::PathBuf, std::path::PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    for (branch, start) in [
+        ("merged", "main"),
+        ("dirty-merged", "merged"),
+        ("unmerged", "main"),
+    ] {
+        let path = project_dir.join(format!("test_{branch}"));
+        add_branch_worktree(&bare, &path, branch, start);
+        std::fs::write(path.join(format!("{branch}.txt")), "work\n").unwrap();
+        git_in(&path, &["add", "."]);
+        git_in(&path, &["commit", "-m", branch]);
+    }
+
+    // Push both merged branches and land them upstream, with a later commit on top
+    for branch in ["merged", "dirty-merged"] {
+        git_in(
+            &project_dir.join(format!("test_{branch}")),
+            &["push", "origin", branch],
+        );
+    }
+    let source = tmp.path().join("source");
+    git_in(
+        &source,
+        &["pull", "--ff-only", bare.to_str().unwrap(), "dirty-merged"],
+    );
+    std::fs::write(source.join("later.txt"), "later\n").unwrap();
+    git_in(&source, &["add", "."]);
+    git_in(&source, &["commit", "-m", "later"]);
+    // Local main has caught up too, so `git branch -d` sees the merges
+    git_in(
+        &main_wt,
+        &["pull", "--ff-only", source.to_str().unwrap(), "main"],
+    );
+
+    std::fs::write(project_dir.join("test_dirty-merged/scratch.txt"), "x").unwrap();
+
+    (tmp, bare, project_dir)
+}
+
+#[test]
+fn remove_merged_yes_removes_merged_and_skips_dirty() {
+    let (_tmp, bare, project_dir) = setup_merged_case();
+
+    common::grov()
+        .args(["remove", "--merged", "--yes"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Removed 1 worktree, deleted 1 branch, skipped 1 dirty",
+        ))
+        .stderr(predicate::str::contains("Skipping dirty-merged"));
+
+    assert!(!project_dir.join("test_merged").exists());
+    assert!(project_dir.join("test_unmerged").exists());
+    assert!(project_dir.join("test_dirty-merged").exists());
+    assert!(project_dir.join("test_main").exists());
+
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["branch", "--list", "merged"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).trim().is_empty());
+}
+
+#[test]
+fn remove_merged_force_includes_dirty() {
+    let (_tmp, _bare, project_dir) = setup_merged_case();
+
+    common::grov()
+        .args(["remove", "--merged", "--yes", "--force"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 2 worktrees"));
+
+    assert!(!project_dir.join("test_merged").exists());
+    assert!(!project_dir.join("test_dirty-merged").exists());
+    assert!(project_dir.join("test_unmerged").exists());
+}
+
+#[test]
+fn remove_merged_includes_gone_upstream() {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let source = tmp.path().join("source");
+
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // A remote branch with its own commit, checked out with tracking
+    git_in(&source, &["checkout", "-b", "shipped"]);
+    std::fs::write(source.join("shipped.txt"), "x\n").unwrap();
+    git_in(&source, &["add", "."]);
+    git_in(&source, &["commit", "-m", "shipped"]);
+    git_in(&source, &["checkout", "main"]);
+    common::grov()
+        .args(["add", "shipped"])
+        .current_dir(&main_wt)
+        .assert()
+        .success();
+
+    // Squash-merged upstream: the remote branch is deleted
+    git_in(&source, &["branch", "-D", "shipped"]);
+
+    common::grov()
+        .args(["remove", "--merged", "--yes"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 worktree"));
+
+    assert!(!project_dir.join("test_shipped").exists());
+}
+
+#[test]
+fn remove_merged_nothing_to_do() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    common::grov()
+        .args(["remove", "--merged"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No merged worktrees to remove."));
+}
+
+#[test]
+fn remove_merged_keeps_branch_at_target_tip() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Freshly created, no commits of its own yet
+    let fresh = project_dir.join("test_fresh");
+    add_branch_worktree(&bare, &fresh, "fresh", "main");
+
+    common::grov()
+        .args(["remove", "--merged", "--yes"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No merged worktrees to remove."));
+
+    assert!(fresh.exists());
+}
+
+#[test]
+fn remove_merged_needs_work_of_its_own() {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let source = tmp.path().join("source");
+    let main_wt = project_dir.join("test_main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["worktree", "add", main_wt.to_str().unwrap(), "main"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Cut from the current main, which origin/main moves past below
+    let old = project_dir.join("test_old");
+    add_branch_worktree(&bare, &old, "old", "main");
+
+    // Never pushed, but merged upstream with a merge commit
+    let local = project_dir.join("test_local");
+    add_branch_worktree(&bare, &local, "local", "main");
+    std::fs::write(local.join("local.txt"), "work\n").unwrap();
+    git_in(&local, &["add", "."]);
+    git_in(&local, &["commit", "-m", "local"]);
+    git_in(
+        &source,
+        &[
+            "pull",
+            "--no-ff",
+            "--no-edit",
+            bare.to_str().unwrap(),
+            "local",
+        ],
+    );
+
+    common::grov()
+        .args(["remove", "--merged", "--yes"])
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 worktree"));
+
+    assert!(!local.exists());
+    assert!(old.exists());
+}
+
+#[test]
+fn remove_merged_non_tty_without_yes_fails() {
+    let (_tmp, _bare, project_dir) = setup_merged_case();
+
+    common::grov()
+        .args(["remove", "--merged"])
+        .current_dir(project_dir.join("test_main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--yes is required when stdin is not a terminal",
+        ));
+}
@@ -0,0 +1,124 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::Path;
+
+use predicates::prelude::*;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=T", "-c", "user.email=t@t"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// A `feature` worktree tracking origin/main, one commit ahead of it, with a
+/// staged file, an untracked file and a stash.
+fn setup() -> (tempfile::TempDir, std::path::PathBuf, std::path::PathBuf) {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let wt = project_dir.join("test_feature");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args(["fetch", "origin"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", &bare)
+        .args([
+            "worktree",
+            "add",
+            "--track",
+            "-b",
+            "feature",
+            wt.to_str().unwrap(),
+            "origin/main",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    git(&wt, &["commit", "--allow-empty", "-m", "feature work"]);
+    std::fs::write(wt.join("README.md"), "stashed\n").unwrap();
+    git(&wt, &["stash", "push", "-m", "half done"]);
+    std::fs::write(wt.join("new.txt"), "staged\n").unwrap();
+    git(&wt, &["add", "new.txt"]);
+    std::fs::write(wt.join("scratch.txt"), "untracked\n").unwrap();
+    (tmp, bare, wt)
+}
+
+#[test]
+fn status_describes_current_worktree() {
+    let (_tmp, bare, wt) = setup();
+    std::fs::write(
+        bare.join(".grov.toml"),
+        "[worktree]\nprefix = \"test\"\n\n[hooks]\npost_add = [\"make setup\"]\n",
+    )
+    .unwrap();
+
+    common::grov()
+        .arg("status")
+        .current_dir(wt.join("."))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("project")
+                .and(predicate::str::contains("feature"))
+                .and(predicate::str::contains("origin/main  ↑1"))
+                .and(predicate::str::contains("1 staged, 1 untracked"))
+                .and(predicate::str::contains("On feature: half done"))
+                .and(predicate::str::contains("post_add (1)")),
+        );
+}
+
+#[test]
+fn status_format_json() {
+    let (_tmp, bare, wt) = setup();
+    std::fs::create_dir(wt.join("sub")).unwrap();
+
+    let output = common::grov()
+        .args(["status", "--format", "json"])
+        .current_dir(wt.join("sub"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["project"], "project");
+    assert_eq!(
+        Path::new(report["bare_repo"].as_str().unwrap()),
+        bare.as_path()
+    );
+    assert_eq!(report["branch"], "feature");
+    assert_eq!(report["upstream"], "origin/main");
+    assert_eq!(report["upstream_gone"], false);
+    assert_eq!(report["ahead"], 1);
+    assert_eq!(report["behind"], 0);
+    assert_eq!(report["base"], "origin/main");
+    assert_eq!(report["base_ahead"], 1);
+    assert_eq!(report["dirty"], true);
+    assert_eq!(report["changes"]["staged"], 1);
+    assert_eq!(report["changes"]["untracked"], 1);
+    assert_eq!(report["stashes"][0]["name"], "stash@{0}");
+    assert_eq!(report["locked"], serde_json::Value::Null);
+    assert_eq!(report["hooks"]["post_add"], serde_json::json!([]));
+}
+
+#[test]
+fn status_outside_worktree_fails() {
+    let (_tmp, bare, _wt) = setup();
+
+    common::grov()
+        .arg("status")
+        .current_dir(bare.parent().unwrap())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not inside a worktree"));
+}
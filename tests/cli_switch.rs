@@ -0,0 +1,157 @@
+#![allow(deprecated)]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::*;
+
+fn add_worktree(bare: &Path, path: &Path, args: &[&str]) {
+    let mut cmd_args = vec!["worktree", "add"];
+    cmd_args.extend(args);
+    cmd_args.push(path.to_str().unwrap());
+    cmd_args.push("main");
+    let output = std::process::Command::new("git")
+        .env("GIT_DIR", bare)
+        .args(&cmd_args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to add worktree");
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap()
+}
+
+#[test]
+fn switch_prints_path_without_wrapper() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let feature_wt = project_dir.join("test_feature");
+    add_worktree(&bare, &feature_wt, &["-b", "feature"]);
+
+    let output = common::grov()
+        .args(["switch", "feature"])
+        .env_remove("GROV_CD_FILE")
+        .current_dir(&main_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let printed = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    assert_eq!(canonical(Path::new(&printed)), canonical(&feature_wt));
+}
+
+#[test]
+fn switch_writes_cd_file() {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let feature_wt = project_dir.join("test_feature");
+    add_worktree(&bare, &feature_wt, &["-b", "feature"]);
+
+    let cd_file = tmp.path().join("cd-target");
+    common::grov()
+        .args(["switch", "test_feature", "--match", "dir"])
+        .env("GROV_CD_FILE", &cd_file)
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    let target = std::fs::read_to_string(&cd_file).unwrap();
+    assert_eq!(canonical(Path::new(&target)), canonical(&feature_wt));
+}
+
+#[test]
+fn switch_unknown_name_fails() {
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+
+    common::grov()
+        .args(["switch", "nope"])
+        .current_dir(&main_wt)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("worktree not found: nope"));
+}
+
+#[test]
+fn add_writes_cd_file_and_skips_hint() {
+    let (tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+
+    let cd_file = tmp.path().join("cd-target");
+    common::grov()
+        .args(["add", "cd-branch", "--base", "main"])
+        .env("GROV_CD_FILE", &cd_file)
+        .current_dir(&main_wt)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("To start working:").not());
+
+    let target = std::fs::read_to_string(&cd_file).unwrap();
+    assert_eq!(
+        canonical(Path::new(&target)),
+        canonical(&project_dir.join("test_cd-branch"))
+    );
+}
+
+#[test]
+fn shell_init_emits_wrapper() {
+    for shell in ["bash", "zsh", "fish"] {
+        common::grov()
+            .args(["shell-init", shell])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("GROV_CD_FILE").and(predicate::str::contains("cd")));
+    }
+}
+
+#[test]
+fn bash_wrapper_changes_directory() {
+    if std::process::Command::new("bash")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        return;
+    }
+
+    let (_tmp, bare, project_dir) = common::create_bare_repo();
+    let main_wt = project_dir.join("test_main");
+    add_worktree(&bare, &main_wt, &[]);
+    let feature_wt = project_dir.join("test_feature");
+    add_worktree(&bare, &feature_wt, &["-b", "feature"]);
+
+    let bin_dir = assert_cmd::cargo::cargo_bin("grov")
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let path = format!(
+        "{}:{}",
+        bin_dir.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+
+    let output = std::process::Command::new("bash")
+        .args([
+            "-c",
+            r#"eval "$(grov shell-init bash)" && grov switch feature && pwd"#,
+        ])
+        .env("PATH", path)
+        .current_dir(&main_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let pwd = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    assert_eq!(canonical(Path::new(&pwd)), canonical(&feature_wt));
+}
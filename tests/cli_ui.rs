@@ -0,0 +1,17 @@
+#![allow(deprecated)]
+
+mod common;
+
+use predicates::prelude::*;
+
+#[test]
+fn ui_requires_a_terminal() {
+    let (_tmp, _bare, project_dir) = common::create_bare_repo();
+
+    common::grov()
+        .arg("ui")
+        .current_dir(&project_dir)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("requires an interactive terminal"));
+}
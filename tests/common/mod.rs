@@ -5,6 +5,23 @@ use std::process::Command;
 
 use tempfile::TempDir;
 
+/// The `grov` binary, isolated from the developer's own setup: `HOME` and
+/// `XDG_CONFIG_HOME` point at an empty directory under the temp dir, so no
+/// global config is read, and `GROV_*` overrides are cleared. Tests set their
+/// own with `.env(...)`.
+pub fn grov() -> assert_cmd::Command {
+    let home = std::env::temp_dir().join(format!("grov-tests-home-{}", std::process::id()));
+    let mut cmd = assert_cmd::Command::cargo_bin("grov").unwrap();
+    for (var, _) in std::env::vars_os() {
+        if var.to_string_lossy().starts_with("GROV_") {
+            cmd.env_remove(var);
+        }
+    }
+    cmd.env("XDG_CONFIG_HOME", home.join(".config"))
+        .env("HOME", home);
+    cmd
+}
+
 /// Create a temporary project with a bare repo at `tmp/project/repo.git`,
 /// a `.grov.toml` with prefix "test", and the source repo for cloning.
 ///
@@ -18,18 +35,7 @@ pub fn create_bare_repo_with_prefix(prefix: &str) -> (TempDir, PathBuf, PathBuf)
     let tmp = TempDir::new().expect("failed to create temp dir");
 
     // Create a normal repo first, make a commit, then clone it bare
-    let source = tmp.path().join("source");
-    std::fs::create_dir_all(&source).unwrap();
-
-    run(&source, &["git", "init", "-b", "main"]);
-    run(&source, &["git", "config", "user.email", "test@test.com"]);
-    run(&source, &["git", "config", "user.name", "Test"]);
-
-    // Create initial commit
-    let file = source.join("README.md");
-    std::fs::write(&file, "# test\n").unwrap();
-    run(&source, &["git", "add", "."]);
-    run(&source, &["git", "commit", "-m", "initial"]);
+    let source = create_source_repo(tmp.path());
 
     // Create project directory
     let project_dir = tmp.path().join("project");
@@ -77,6 +83,44 @@ pub fn create_bare_repo_with_prefix(prefix: &str) -> (TempDir, PathBuf, PathBuf)
     (tmp, bare, project_dir)
 }
 
+/// Create a non-bare repo at `dir/source` on `main` with one commit.
+///
+/// Serves as the "remote" that projects are cloned from.
+pub fn create_source_repo(dir: &Path) -> PathBuf {
+    let source = dir.join("source");
+    std::fs::create_dir_all(&source).unwrap();
+
+    run(&source, &["git", "init", "-b", "main"]);
+    run(&source, &["git", "config", "user.email", "test@test.com"]);
+    run(&source, &["git", "config", "user.name", "Test"]);
+
+    // Create initial commit
+    let file = source.join("README.md");
+    std::fs::write(&file, "# test\n").unwrap();
+    run(&source, &["git", "add", "."]);
+    run(&source, &["git", "commit", "-m", "initial"]);
+
+    source
+}
+
+/// Create a bare clone of the source repo at `dir/source.git`, like a repo
+/// on a hosting service. Use it as the `--url` for `grov init`.
+pub fn create_bare_remote(dir: &Path) -> PathBuf {
+    let source = create_source_repo(dir);
+    let remote = dir.join("source.git");
+    run(
+        dir,
+        &[
+            "git",
+            "clone",
+            "--bare",
+            source.to_str().unwrap(),
+            remote.to_str().unwrap(),
+        ],
+    );
+    remote
+}
+
 fn run(dir: &Path, args: &[&str]) {
     let status = Command::new(args[0])
         .args(&args[1..])